@@ -0,0 +1,202 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::structures::version::Version;
+use crate::{
+    structures::{project::Project, ID},
+    Error, Ferinth, Result,
+};
+use bytes::Bytes;
+use futures_util::{stream, StreamExt};
+use reqwest::Url;
+use std::collections::HashMap;
+
+impl Ferinth {
+    /// Download the file at `url`
+    ///
+    /// The authorisation header is not sent with these requests,
+    /// but rate limited requests are retried and failures are mapped to errors like those of API requests.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_version = modrinth.get_version("xuWxRZPd").await?;
+    /// let primary_file = sodium_version.primary_file().unwrap();
+    /// let contents = modrinth.download(primary_file.url.clone()).await?;
+    /// assert!(primary_file.hashes.verify(&contents));
+    /// # Ok(()) }
+    /// ```
+    pub async fn download(&self, url: Url) -> Result<Bytes> {
+        self.read_body(self.get_download(url).await?).await
+    }
+
+    /// Download a file from the first of the mirrors in `urls` that succeeds.
+    /// If all of them fail, the error from the last one is returned.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_version = modrinth.get_version("xuWxRZPd").await?;
+    /// let contents = modrinth.download_from_mirrors(&[
+    ///     "https://mirror.invalid/sodium.jar".parse().unwrap(),
+    ///     sodium_version.primary_file().unwrap().url.clone(),
+    /// ]).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn download_from_mirrors(&self, urls: &[Url]) -> Result<Bytes> {
+        let mut last_error = Error::NoDownloads;
+        for url in urls {
+            match self.download(url.clone()).await {
+                Ok(contents) => return Ok(contents),
+                Err(error) => last_error = error,
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Download the icons of `projects`, with at most `concurrency` downloads at a time.
+    /// The map is keyed by the projects' IDs, and projects without icons or whose icons failed to download are not present.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let projects = modrinth.get_multiple_projects(&["AANobbMI", "P7dR8mSH"]).await?;
+    /// let icons = modrinth.prefetch_icons(&projects, 4).await;
+    /// assert!(icons.contains_key("AANobbMI"));
+    /// # Ok(()) }
+    /// ```
+    pub async fn prefetch_icons(
+        &self,
+        projects: &[Project],
+        concurrency: usize,
+    ) -> HashMap<ID, Bytes> {
+        stream::iter(projects.iter().filter_map(|project| {
+            project
+                .icon_url
+                .clone()
+                .map(|icon_url| async move { (project.id.clone(), self.download(icon_url).await) })
+        }))
+        .buffer_unordered(concurrency.max(1))
+        .filter_map(|(id, icon)| async move { icon.ok().map(|icon| (id, icon)) })
+        .collect()
+        .await
+    }
+
+    /// Download `file` into `writer`, streaming it rather than holding all of it in memory, and verify it.
+    ///
+    /// `progress` is called with the number of bytes written so far after every chunk.
+    /// If the contents don't match the file's hashes, [`Error::HashMismatch`] is returned,
+    /// but the contents will have already been written, so they should be discarded.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_version = modrinth.get_version("xuWxRZPd").await?;
+    /// let primary_file = sodium_version.primary_file().unwrap();
+    /// let mut contents = Vec::new();
+    /// modrinth
+    ///     .download_file_to(primary_file, &mut contents, |written| {
+    ///         println!("{}/{} bytes", written, primary_file.size);
+    ///     })
+    ///     .await?;
+    /// assert!(contents.len() == primary_file.size);
+    /// # Ok(()) }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn download_file_to(
+        &self,
+        file: &crate::structures::version::VersionFile,
+        mut writer: impl tokio::io::AsyncWrite + Unpin,
+        mut progress: impl FnMut(u64),
+    ) -> Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut response = self.get_download(file.url.clone()).await?;
+        if let Some(limit) = self.max_response_size {
+            if response.content_length().is_some_and(|length| length > limit) {
+                return Err(Error::ResponseTooLarge(limit));
+            }
+        }
+
+        let mut context = file.hashes.context();
+        let mut written = 0;
+        while let Some(chunk) = response.chunk().await? {
+            written += chunk.len() as u64;
+            if let Some(limit) = self.max_response_size.filter(|&limit| written > limit) {
+                return Err(Error::ResponseTooLarge(limit));
+            }
+            context.update(&chunk);
+            writer.write_all(&chunk).await?;
+            progress(written);
+        }
+        writer.flush().await?;
+
+        if file.hashes.check(&context.finish()) {
+            Ok(())
+        } else {
+            Err(Error::HashMismatch)
+        }
+    }
+
+    /// Download the primary file of `version` into the directory `dir`, verify it, and return the path it was written to.
+    ///
+    /// The file is named using its `filename`. If a file with that name already exists,
+    /// an [`AlreadyExists`](std::io::ErrorKind::AlreadyExists) error is returned unless `overwrite` is set.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_version = modrinth.get_version("xuWxRZPd").await?;
+    /// let path = modrinth.download_primary_file_to(&sodium_version, &std::env::temp_dir(), true).await?;
+    /// assert!(path.exists());
+    /// # Ok(()) }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn download_primary_file_to(
+        &self,
+        version: &Version,
+        dir: &std::path::Path,
+        overwrite: bool,
+    ) -> Result<std::path::PathBuf> {
+        use std::io::{Error as IOError, ErrorKind};
+        use tokio::io::AsyncWriteExt;
+
+        let file = version.primary_file().ok_or(Error::NoDownloads)?;
+        // Make sure the filename can't escape `dir`
+        if std::path::Path::new(&file.filename).file_name()
+            != Some(std::ffi::OsStr::new(&file.filename))
+        {
+            return Err(IOError::new(
+                ErrorKind::InvalidInput,
+                format!("Invalid filename {:?}", file.filename),
+            )
+            .into());
+        }
+
+        let contents = self.download(file.url.clone()).await?;
+        if !file.hashes.verify(&contents) {
+            return Err(Error::HashMismatch);
+        }
+
+        let path = dir.join(&file.filename);
+        let mut options = tokio::fs::OpenOptions::new();
+        options.write(true);
+        if overwrite {
+            options.create(true).truncate(true);
+        } else {
+            options.create_new(true);
+        }
+        let mut output = options.open(&path).await?;
+        output.write_all(&contents).await?;
+        output.flush().await?;
+        Ok(path)
+    }
+}
@@ -1,18 +1,40 @@
+pub mod download;
+#[cfg(feature = "modpack")]
+pub mod modpack;
 pub mod project;
+pub mod search;
 pub mod tag;
 pub mod team;
 pub mod user;
 pub mod version;
 pub mod version_file;
 
-use crate::{Error, Result};
+use crate::{structures::version::HashAlgorithm, Error, Result};
 
-/// Verify that a given string `input` is compliant with Modrinth IDs or slugs
+/// Verify that a given string `input` is a Modrinth ID or slug
 pub(crate) fn check_id_slug(input: &str) -> Result<()> {
-    // regex taken from [Modrinth documentation](https://docs.modrinth.com/api-spec/#tag/project_model)
-    lazy_regex::regex_is_match!(r#"^[\w!@$()`.+,"\-']{3,64}$"#, input)
+    // IDs are 8 base62 digits
+    let is_id = lazy_regex::regex_is_match!("^[0-9A-Za-z]{8}$", input);
+    // regex taken from [Modrinth documentation](https://docs.modrinth.com/api-spec/#tag/project_model),
+    // with `\w` restricted to ASCII like the API's
+    let is_slug = lazy_regex::regex_is_match!(r#"^[A-Za-z0-9_!@$()`.+,"\-']{3,64}$"#, input);
+    (is_id || is_slug)
         .then_some(())
-        .ok_or(Error::NotBase62)
+        .ok_or_else(|| Error::InvalidIDorSlug(input.to_owned()))
+}
+
+/// Verify that each of `inputs` is compliant with Modrinth IDs or slugs, collecting them to a list
+pub(crate) fn check_id_slugs(
+    inputs: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<Vec<String>> {
+    inputs
+        .into_iter()
+        .map(|input| {
+            let input = input.as_ref();
+            check_id_slug(input)?;
+            Ok(input.to_string())
+        })
+        .collect()
 }
 
 /// Verify that a given string `input` is SHA1 compliant
@@ -23,3 +45,20 @@ pub(crate) fn check_sha1_hash(input: &str) -> Result<()> {
         false => Err(Error::NotSHA1),
     }
 }
+
+/// Verify that a given string `input` is SHA512 compliant
+pub(crate) fn check_sha512_hash(input: &str) -> Result<()> {
+    // Check that all 128 characters are SHA512 compliant
+    match lazy_regex::regex_is_match!("^[a-f0-9]{128}$", input) {
+        true => Ok(()),
+        false => Err(Error::NotSHA512),
+    }
+}
+
+/// Verify that a given string `input` is a hash computed with `algorithm`
+pub(crate) fn check_hash(input: &str, algorithm: &HashAlgorithm) -> Result<()> {
+    match algorithm {
+        HashAlgorithm::SHA1 => check_sha1_hash(input),
+        HashAlgorithm::SHA512 => check_sha512_hash(input),
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    structures::{
+        modpack::{ModpackFile, ModpackIndex},
+        version::{HashAlgorithm, Version},
+    },
+    Error, Ferinth, Result,
+};
+use bytes::Bytes;
+use std::collections::HashMap;
+
+impl Ferinth {
+    /// Get the versions of the files in the modpack `index` that are hosted on Modrinth.
+    /// The map is keyed by the files' `path`, files that are not on Modrinth will not be present.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::modpack::ModpackIndex;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let index = ModpackIndex::from_slice(br#"{
+    ///     "formatVersion": 1,
+    ///     "game": "minecraft",
+    ///     "versionId": "1.0.0",
+    ///     "name": "Example Pack",
+    ///     "files": [{
+    ///         "path": "mods/sodium-fabric-mc1.19.2-0.4.4+build.18.jar",
+    ///         "hashes": {
+    ///             "sha1": "795d4c12bffdb1b21eed5ff87c07ce5ca3c0dcbf",
+    ///             "sha512": ""
+    ///         },
+    ///         "downloads": ["https://cdn.modrinth.com/data/AANobbMI/versions/mc1.19.2-0.4.4/sodium-fabric-mc1.19.2-0.4.4%2Bbuild.18.jar"],
+    ///         "fileSize": 0
+    ///     }],
+    ///     "dependencies": { "minecraft": "1.19.2" }
+    /// }"#)?;
+    /// let versions = modrinth.get_modpack_file_versions(&index).await?;
+    /// assert!(versions["mods/sodium-fabric-mc1.19.2-0.4.4+build.18.jar"].project_id == "AANobbMI");
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_modpack_file_versions(
+        &self,
+        index: &ModpackIndex,
+    ) -> Result<HashMap<String, Version>> {
+        let hashes: Vec<_> = index
+            .files
+            .iter()
+            .map(|file| file.hashes.sha1.as_str())
+            .collect();
+        let versions = self
+            .get_versions_from_hashes(&hashes, HashAlgorithm::SHA1)
+            .await?;
+        Ok(index
+            .files
+            .iter()
+            .filter_map(|file| {
+                versions
+                    .get(&file.hashes.sha1)
+                    .map(|version| (file.path.clone(), version.clone()))
+            })
+            .collect())
+    }
+
+    /// Download the modpack `file`, trying each of its download links in order
+    /// until one succeeds and the contents match the file's hashes
+    pub async fn download_modpack_file(&self, file: &ModpackFile) -> Result<Bytes> {
+        let mut last_error = Error::NoDownloads;
+        for url in &file.downloads {
+            match self.download(url.clone()).await {
+                Ok(contents) if file.verify(&contents) => return Ok(contents),
+                Ok(_) => last_error = Error::HashMismatch,
+                Err(error) => last_error = error,
+            }
+        }
+        Err(last_error)
+    }
+}
@@ -1,12 +1,19 @@
-use super::check_id_slug;
+use super::{check_id_slug, check_id_slugs};
 use crate::{
-    request::API_URL_BASE, structures::project::*, url_join_ext::UrlJoinExt, Ferinth,
-    Result,
+    structures::{project::*, tag::GameVersion, version::ModLoader, ID},
+    url_join_ext::UrlJoinExt,
+    Error, Ferinth, Result,
 };
 
 impl Ferinth {
     /// Get a project with ID `project_id`
     ///
+    /// Withheld and rejected projects are returned with their moderation `status`,
+    /// like from [`Ferinth::get_multiple_projects`],
+    /// use [`ProjectStatus::is_hidden_by_moderators`] to check for them.
+    /// The API may instead respond as if such a project doesn't exist,
+    /// in which case [`Error::NotFound`] is returned noting that the project may be hidden.
+    ///
     /// Example:
     /// ```rust
     /// # #[tokio::main]
@@ -24,12 +31,28 @@ impl Ferinth {
     ///     ok_zoomer_mod.title,
     ///     "Ok Zoomer",
     /// );
+    ///
+    /// // IDs can be borrowed or owned, such as the IDs in other structures
+    /// let sodium_version = modrinth.get_version("xuWxRZPd").await?;
+    /// let sodium_mod = modrinth.get_project(sodium_version.project_id).await?;
+    /// assert_eq!(
+    ///     sodium_mod.title,
+    ///     "Sodium",
+    /// );
     /// # Ok(()) }
     /// ```
-    pub async fn get_project(&self, project_id: &str) -> Result<Project> {
+    pub async fn get_project(&self, project_id: impl AsRef<str>) -> Result<Project> {
+        let project_id = project_id.as_ref();
         check_id_slug(project_id)?;
-        self.get(API_URL_BASE.join_all(vec!["project", project_id]))
+        self.get(self.base_url.join_all(vec!["project", project_id]))
             .await
+            .map_err(|error| match error {
+                Error::NotFound(body) => Error::NotFound(format!(
+                    "project `{}` doesn't exist, or is withheld or rejected and hidden from you ({})",
+                    project_id, body
+                )),
+                error => error,
+            })
     }
 
     /// Get multiple projects with IDs `project_ids`
@@ -48,17 +71,44 @@ impl Ferinth {
     /// assert!(mods.len() == 4);
     /// # Ok(()) }
     /// ```
-    pub async fn get_multiple_projects(&self, project_ids: &[&str]) -> Result<Vec<Project>> {
-        for project_id in project_ids {
-            check_id_slug(project_id)?;
-        }
+    pub async fn get_multiple_projects(
+        &self,
+        project_ids: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Vec<Project>> {
+        let project_ids = check_id_slugs(project_ids)?;
         self.get_with_query(
-            API_URL_BASE.join_all(vec!["projects"]),
-            &[("ids", &serde_json::to_string(project_ids)?)],
+            self.base_url.join_all(vec!["projects"]),
+            &[("ids", &serde_json::to_string(&project_ids)?)],
         )
         .await
     }
 
+    /// Get the projects with IDs or slugs `project_ids`,
+    /// using the single project endpoint for one project and the bulk endpoint for more.
+    /// No request is made if `project_ids` is empty.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// assert!(modrinth.get_projects(Vec::<String>::new()).await?.is_empty());
+    /// assert!(modrinth.get_projects(&["sodium"]).await?.len() == 1);
+    /// assert!(modrinth.get_projects(&["sodium", "lithium"]).await?.len() == 2);
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_projects(
+        &self,
+        project_ids: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Vec<Project>> {
+        let project_ids = check_id_slugs(project_ids)?;
+        match project_ids.as_slice() {
+            [] => Ok(Vec::new()),
+            [project_id] => Ok(vec![self.get_project(project_id).await?]),
+            project_ids => self.get_multiple_projects(project_ids).await,
+        }
+    }
+
     /// Check if the given ID or slug refers to an existing project.
     /// If so, the ID of the project will be returned.
     ///
@@ -71,29 +121,152 @@ impl Ferinth {
     /// assert!(project_id == "AANobbMI");
     /// # Ok(()) }
     /// ```
-    pub async fn does_exist(&self, project_id: &str) -> Result<String> {
+    pub async fn does_exist(&self, project_id: impl AsRef<str>) -> Result<String> {
+        let project_id = project_id.as_ref();
         #[derive(serde::Deserialize)]
         struct Response {
             id: String,
         }
         check_id_slug(project_id)?;
         let res: Response = self
-            .get(API_URL_BASE.join_all(vec!["project", project_id, "check"]))
+            .get(self.base_url.join_all(vec!["project", project_id, "check"]))
             .await?;
         Ok(res.id)
     }
 
-    /* TODO: Binary POST body required
+    /// Check whether the given ID or slug refers to an existing project, without getting the whole project.
+    /// If so, the ID of the project is returned, otherwise `None` is.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// assert!(modrinth.check_project_exists("sodium").await? == Some("AANobbMI".into()));
+    /// assert!(modrinth.check_project_exists("this-project-does-not-exist").await?.is_none());
+    /// # Ok(()) }
+    /// ```
+    pub async fn check_project_exists(&self, project_id: impl AsRef<str>) -> Result<Option<ID>> {
+        match self.does_exist(project_id).await {
+            Ok(id) => Ok(Some(id)),
+            Err(Error::NotFound(_)) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
 
-    /// Add the given gallery `image`, with the file `ext`ention and an optional `title`, to `project_id`.
-    /// State whether the image should be `featured` or not, and optionally provide a `description`.
+    /// Get the current slug of the project with ID `project_id`.
     ///
-    /// The image data can have a maximum size of `5 MiB`
+    /// Slugs can be changed by the project's authors, but IDs can't,
+    /// so this can be used to keep links built from a stored ID up to date.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let slug = modrinth.resolve_current_slug("AANobbMI").await?;
+    /// assert!(slug == "sodium");
+    /// # Ok(()) }
+    /// ```
+    pub async fn resolve_current_slug(&self, project_id: impl AsRef<str>) -> Result<String> {
+        Ok(self.get_project(project_id).await?.slug)
+    }
+
+    /// Check if the current user is following the project with ID or slug `project_id`.
+    ///
+    /// Modrinth doesn't have an endpoint for this,
+    /// so this fetches the current user's followed projects and searches them.
     ///
     /// REQUIRES AUTHENTICATION!
     ///
     /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// let is_following = modrinth.is_following_project(env!("TEST_PROJECT_ID")).await?;
+    /// # Ok(()) }
     /// ```
+    pub async fn is_following_project(&self, project_id: impl AsRef<str>) -> Result<bool> {
+        let project_id = project_id.as_ref();
+        check_id_slug(project_id)?;
+        let current_user = self.get_current_user().await?;
+        Ok(self
+            .followed_projects(&current_user.id)
+            .await?
+            .iter()
+            .any(|project| project.id == project_id || project.slug == project_id))
+    }
+
+    /// Get all the game versions supported by any version of the project with ID `project_id`,
+    /// deduplicated and sorted from oldest to newest.
+    ///
+    /// If the game version tag can't be fetched, the game versions are sorted by name instead.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let game_versions = modrinth.get_project_game_versions("AANobbMI").await?;
+    /// assert!(game_versions.contains(&"1.19.2".to_string()));
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_project_game_versions(
+        &self,
+        project_id: impl AsRef<str>,
+    ) -> Result<Vec<String>> {
+        let mut game_versions = self
+            .list_versions(project_id)
+            .await?
+            .into_iter()
+            .flat_map(|version| version.game_versions)
+            .collect::<std::collections::HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        GameVersion::sort_versions(&self.list_game_versions_or_empty().await, &mut game_versions);
+        Ok(game_versions)
+    }
+
+    /// Get all the loaders supported by any version of the project with ID `project_id`, deduplicated and sorted
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::version::ModLoader;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let loaders = modrinth.get_project_loaders("AANobbMI").await?;
+    /// assert!(loaders.contains(&ModLoader::Fabric));
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_project_loaders(&self, project_id: impl AsRef<str>) -> Result<Vec<ModLoader>> {
+        let mut loaders = self
+            .list_versions(project_id)
+            .await?
+            .into_iter()
+            .flat_map(|version| version.loaders)
+            .collect::<Vec<_>>();
+        loaders.sort();
+        loaders.dedup();
+        Ok(loaders)
+    }
+
+    /// Change the fields of the project with ID or slug `project_id` that are set in `edits`.
+    ///
+    /// If `edits` sets the project's status, the project is fetched first to check that
+    /// the change is allowed, returning [`Error::InvalidStatusTransition`](crate::Error::InvalidStatusTransition) if it isn't.
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```ignore
+    /// # use ferinth::structures::project::ProjectEdit;
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), ferinth::Error> {
     /// # let modrinth = ferinth::Ferinth::new(
@@ -102,46 +275,271 @@ impl Ferinth {
     /// #     None,
     /// #     Some(env!("MODRINTH_TOKEN")),
     /// # )?;
-    /// modrinth.add_gallery_image(
+    /// modrinth.modify_project(env!("TEST_PROJECT_ID"), &ProjectEdit {
+    ///     description: Some("A new description".into()),
+    ///     ..Default::default()
+    /// }).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn modify_project(
+        &self,
+        project_id: impl AsRef<str>,
+        edits: &ProjectEdit,
+    ) -> Result<()> {
+        let project_id = project_id.as_ref();
+        check_id_slug(project_id)?;
+        if let Some(status) = &edits.status {
+            let current = self.get_project(project_id).await?.status;
+            if &current != status {
+                current.check_transition(status)?;
+            }
+        }
+        self.patch(self.base_url.join_all(vec!["project", project_id]), edits)
+            .await
+    }
+
+    /// Delete the project with ID or slug `project_id`, along with all of its versions and files.
+    ///
+    /// WARNING: THIS IS IRREVERSIBLE! The project can't be recovered once it's deleted.
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```ignore
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// modrinth.delete_project("XXXXXXXX").await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn delete_project(&self, project_id: impl AsRef<str>) -> Result<()> {
+        let project_id = project_id.as_ref();
+        check_id_slug(project_id)?;
+        self.delete(self.base_url.join_all(vec!["project", project_id]))
+            .await
+    }
+
+    /// Change the icon of `project_id` to the given `image`, with the file `ext`ention.
+    ///
+    /// The image data can have a maximum size of `256 KiB`
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```ignore
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// modrinth.change_project_icon(
     ///     env!("TEST_PROJECT_ID"),
-    ///     &std::fs::read("test_image.png").expect("Failed to read test image"),
+    ///     std::fs::read("test_image.png").expect("Failed to read test image"),
     ///     ferinth::structures::project::FileExt::PNG,
-    ///     false,
-    ///     Some("Test image".to_string()),
-    ///     None,
     /// ).await?;
     /// # Ok(()) }
     /// ```
+    pub async fn change_project_icon(
+        &self,
+        project_id: impl AsRef<str>,
+        image: Vec<u8>,
+        ext: FileExt,
+    ) -> Result<()> {
+        let project_id = project_id.as_ref();
+        check_id_slug(project_id)?;
+        self.patch_raw_with_query(
+            self.base_url.join_all(vec!["project", project_id, "icon"]),
+            image.into(),
+            ext.mime_type(),
+            &[("ext", ext.as_str())],
+        )
+        .await
+    }
+
+    /// Remove the icon of `project_id`
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```ignore
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// modrinth.delete_project_icon(env!("TEST_PROJECT_ID")).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn delete_project_icon(&self, project_id: impl AsRef<str>) -> Result<()> {
+        let project_id = project_id.as_ref();
+        check_id_slug(project_id)?;
+        self.delete(self.base_url.join_all(vec!["project", project_id, "icon"]))
+            .await
+    }
 
+    /// Add the given gallery `image`, with the file `ext`ention, to `project_id`.
+    /// Whether the image is featured, its title, description, and ordering are set by `options`.
+    ///
+    /// The image data can have a maximum size of `5 MiB`
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```ignore
+    /// # use ferinth::structures::project::GalleryImageOptions;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// modrinth.add_gallery_image(
+    ///     env!("TEST_PROJECT_ID"),
+    ///     std::fs::read("test_image.png").expect("Failed to read test image"),
+    ///     ferinth::structures::project::FileExt::PNG,
+    ///     &GalleryImageOptions {
+    ///         title: Some("Test image".into()),
+    ///         ..Default::default()
+    ///     },
+    /// ).await?;
+    /// # Ok(()) }
+    /// ```
     pub async fn add_gallery_image(
         &self,
-        project_id: &str,
-        image: &[u8],
+        project_id: impl AsRef<str>,
+        image: Vec<u8>,
         ext: FileExt,
-        featured: bool,
-        title: Option<String>,
-        description: Option<String>,
+        options: &GalleryImageOptions,
     ) -> Result<()> {
+        let project_id = project_id.as_ref();
         check_id_slug(project_id)?;
-        self.post_with_query(
-            API_URL_BASE.join_all(vec!["project", project_id, "gallery"]),
+        let mut query = vec![
+            ("ext", ext.as_str().to_string()),
+            ("featured", options.featured.to_string()),
+        ];
+        if let Some(title) = &options.title {
+            query.push(("title", title.clone()));
+        }
+        if let Some(description) = &options.description {
+            query.push(("description", description.clone()));
+        }
+        if let Some(ordering) = options.ordering {
+            query.push(("ordering", ordering.to_string()));
+        }
+        self.post_raw_with_query(
+            self.base_url.join_all(vec!["project", project_id, "gallery"]),
+            image.into(),
+            ext.mime_type(),
+            &query,
+        )
+        .await
+    }
+
+    /// Add the given gallery `image` to the end of `project_id`'s gallery.
+    ///
+    /// Unlike [`Ferinth::add_gallery_image`] without an ordering, this gets the project's gallery
+    /// and gives the image an ordering one greater than the largest existing one,
+    /// so that images added one after another stay in the order they were added.
+    /// The ordering in `options` is ignored.
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```ignore
+    /// # use ferinth::structures::project::GalleryImageOptions;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// modrinth.append_gallery_image(
+    ///     env!("TEST_PROJECT_ID"),
+    ///     std::fs::read("test_image.png").expect("Failed to read test image"),
+    ///     ferinth::structures::project::FileExt::PNG,
+    ///     &GalleryImageOptions {
+    ///         title: Some("Test image".into()),
+    ///         ..Default::default()
+    ///     },
+    /// ).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn append_gallery_image(
+        &self,
+        project_id: impl AsRef<str>,
+        image: Vec<u8>,
+        ext: FileExt,
+        options: &GalleryImageOptions,
+    ) -> Result<()> {
+        let project_id = project_id.as_ref();
+        let ordering = self
+            .get_project(project_id)
+            .await?
+            .gallery
+            .iter()
+            .filter_map(|image| image.ordering)
+            .max()
+            .map_or(0, |max| max + 1);
+        self.add_gallery_image(
+            project_id,
             image,
-            &{
-                let mut query = vec![
-                    ("ext", serde_json::to_string(&ext)?),
-                    ("featured", serde_json::to_string(&featured)?),
-                ];
-                if let Some(title) = title {
-                    query.push(("title", title));
-                }
-                if let Some(description) = description {
-                    query.push(("description", description));
-                }
-                query
+            ext,
+            &GalleryImageOptions {
+                ordering: Some(ordering),
+                ..options.clone()
             },
         )
         .await
-    } */
+    }
+
+    /// Remove the gallery image with `url` from `project_id`'s gallery
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```ignore
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// let project = modrinth.get_project(env!("TEST_PROJECT_ID")).await?;
+    /// if let Some(image) = project.gallery.first() {
+    ///     modrinth.delete_gallery_image(&project.id, &image.url).await?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn delete_gallery_image(
+        &self,
+        project_id: impl AsRef<str>,
+        url: &reqwest::Url,
+    ) -> Result<()> {
+        let project_id = project_id.as_ref();
+        check_id_slug(project_id)?;
+        self.delete_with_query(
+            self.base_url.join_all(vec!["project", project_id, "gallery"]),
+            &[("url", url.as_str())],
+        )
+        .await
+    }
 
     /// Get the dependencies of the project with ID `project_id`
     ///
@@ -155,12 +553,48 @@ impl Ferinth {
     /// assert!(fabric_api.projects.is_empty());
     /// # Ok(()) }
     /// ```
-    pub async fn get_project_dependencies(&self, project_id: &str) -> Result<ProjectDependencies> {
+    pub async fn get_project_dependencies(
+        &self,
+        project_id: impl AsRef<str>,
+    ) -> Result<ProjectDependencies> {
+        let project_id = project_id.as_ref();
         check_id_slug(project_id)?;
-        self.get(API_URL_BASE.join_all(vec!["project", project_id, "dependencies"]))
+        self.get(self.base_url.join_all(vec!["project", project_id, "dependencies"]))
             .await
     }
 
+    /// Get the project with ID or slug `project_id` along with its versions, team members, and dependencies, concurrently.
+    ///
+    /// Only a failure to get the project itself is returned as an error,
+    /// failures to get the other parts are stored in their fields of the bundle.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let bundle = modrinth.get_project_bundle("sodium").await?;
+    /// assert!(bundle.project.id == "AANobbMI");
+    /// assert!(!bundle.versions?.is_empty());
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_project_bundle(&self, project_id: impl AsRef<str>) -> Result<ProjectBundle> {
+        let project_id = project_id.as_ref();
+        let (project, versions, members, dependencies) = futures_util::future::join4(
+            self.get_project(project_id),
+            self.list_versions(project_id),
+            self.list_project_team_members(project_id),
+            self.get_project_dependencies(project_id),
+        )
+        .await;
+        Ok(ProjectBundle {
+            project: project?,
+            versions,
+            members,
+            dependencies,
+        })
+    }
+
     /// Follow the given `project_id`.
     /// 
     /// REQUIRES AUTHENTICATION!
@@ -175,24 +609,98 @@ impl Ferinth {
     /// #     None,
     /// #     Some(env!("MODRINTH_TOKEN")),
     /// # )?;
-    /// # if let Err(ferinth::Error::ReqwestError(error)) =
-    /// modrinth.follow(env!("TEST_PROJECT_ID")).await
-    /// # {   if let Some(status) = error.status()  {
-    /// #         // The test project might have already been followed
-    /// #         if status.as_u16() == 400 {
-    /// #             return Ok(());
-    /// #         }
-    /// #     }
-    /// #     return Err(error.into());
+    /// let result = modrinth.follow(env!("TEST_PROJECT_ID")).await;
+    /// # match result {
+    /// #     // The test project might have already been followed
+    /// #     Err(ferinth::Error::Status(status, _)) if status.as_u16() == 400 => {}
+    /// #     result => result?,
     /// # }
     /// # Ok(()) }
     /// ```
-    pub async fn follow(&self, project_id: &str) -> Result<()> {
+    pub async fn follow(&self, project_id: impl AsRef<str>) -> Result<()> {
+        let project_id = project_id.as_ref();
         check_id_slug(project_id)?;
-        self.post(
-            API_URL_BASE.join_all(vec!["project", project_id, "follow"]),
-            "",
+        self.post_no_content(self.base_url.join_all(vec!["project", project_id, "follow"]))
+            .await
+    }
+
+    /// Unfollow the given `project_id`.
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// let result = modrinth.unfollow(env!("TEST_PROJECT_ID")).await;
+    /// # match result {
+    /// #     // The test project might not have been followed
+    /// #     Err(ferinth::Error::Status(status, _)) if status.as_u16() == 400 => {}
+    /// #     result => result?,
+    /// # }
+    /// # Ok(()) }
+    /// ```
+    pub async fn unfollow(&self, project_id: impl AsRef<str>) -> Result<()> {
+        let project_id = project_id.as_ref();
+        check_id_slug(project_id)?;
+        self.delete(self.base_url.join_all(vec!["project", project_id, "follow"]))
+            .await
+    }
+
+    /// Check whether each of `project`'s external links can be reached
+    ///
+    /// This sends a HEAD request (or a GET request if the host doesn't allow HEAD)
+    /// to each link, which are on hosts other than Modrinth. The authorisation header is not sent with these requests.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_mod = modrinth.get_project("AANobbMI").await?;
+    /// for (kind, result) in modrinth.validate_project_links(&sodium_mod).await {
+    ///     if let Err(error) = result {
+    ///         println!("The {:?} link is broken: {}", kind, error);
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn validate_project_links(&self, project: &Project) -> Vec<(LinkKind, Result<()>)> {
+        futures_util::future::join_all(
+            project
+                .links()
+                .into_iter()
+                .map(|(kind, url)| async move { (kind, self.check_link(url.clone()).await) }),
         )
         .await
     }
+
+    /// Check that `url` responds successfully
+    async fn check_link(&self, url: reqwest::Url) -> Result<()> {
+        use reqwest::{Method, StatusCode};
+
+        match self
+            .send(self.unauthorised_request(Method::HEAD, url.clone()))
+            .await
+        {
+            Err(Error::Status(StatusCode::METHOD_NOT_ALLOWED, _))
+            | Err(Error::UnexpectedResponse {
+                status: StatusCode::METHOD_NOT_ALLOWED,
+                ..
+            }) => {
+                self.send(self.unauthorised_request(Method::GET, url))
+                    .await?;
+            }
+            result => {
+                result?;
+            }
+        }
+        Ok(())
+    }
 }
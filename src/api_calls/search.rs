@@ -0,0 +1,153 @@
+use crate::{
+    structures::{search::*, Number},
+    url_join_ext::UrlJoinExt,
+    Ferinth, Result,
+};
+use futures_util::{stream, Stream, TryStreamExt};
+
+impl Ferinth {
+    /// Search for projects matching `query`, sorted by `index`.
+    /// `offset` results are skipped, and at most `limit` results are returned.
+    ///
+    /// The results must match at least one facet from every group in `facets`,
+    /// see [`Facet`] for how they are grouped.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::search::{Facet, SearchIndex};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let results = modrinth.search(
+    ///     "sodium",
+    ///     SearchIndex::Relevance,
+    ///     0,
+    ///     10,
+    ///     &[vec![Facet::Categories("fabric".into())]],
+    /// ).await?;
+    /// assert!(results.hits.iter().any(|hit| hit.project_id == "AANobbMI"));
+    /// # Ok(()) }
+    /// ```
+    pub async fn search(
+        &self,
+        query: &str,
+        index: SearchIndex,
+        offset: Number,
+        limit: Number,
+        facets: &[Vec<Facet>],
+    ) -> Result<SearchResponse> {
+        let mut params = vec![
+            ("query", query.to_string()),
+            ("index", index.as_str().to_string()),
+            ("offset", offset.to_string()),
+            ("limit", limit.to_string()),
+        ];
+        if !facets.is_empty() {
+            params.push(("facets", Facet::encode(facets)?));
+        }
+        self.get_with_query(self.base_url.join_all(vec!["search"]), &params)
+            .await
+    }
+
+    /// Count the projects matching `query` and `facets` that would also match each of the `candidates`,
+    /// such as to show the number of results next to each filter in a faceted search.
+    ///
+    /// The API doesn't return facet counts, so a search with a limit of zero is made for every candidate,
+    /// with the candidate added to `facets` as a group of its own. The counts are in the same order as `candidates`.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::search::Facet;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let candidates = [
+    ///     Facet::Categories("fabric".into()),
+    ///     Facet::Categories("forge".into()),
+    /// ];
+    /// for (facet, count) in modrinth.facet_counts("sodium", &[], &candidates).await? {
+    ///     println!("{}: {}", facet, count);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn facet_counts<'a>(
+        &self,
+        query: &str,
+        facets: &[Vec<Facet>],
+        candidates: &'a [Facet],
+    ) -> Result<Vec<(&'a Facet, Number)>> {
+        futures_util::future::try_join_all(candidates.iter().map(|candidate| async move {
+            let mut facets = facets.to_vec();
+            facets.push(vec![candidate.clone()]);
+            let response = self
+                .search(query, SearchIndex::Relevance, 0, 0, &facets)
+                .await?;
+            Ok((candidate, response.total_hits))
+        }))
+        .await
+    }
+
+    /// Search for projects like [`Ferinth::search`], starting from the first page of at most `limit` results.
+    /// Use [`SearchPage::next`] to get the following pages.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::search::SearchIndex;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let page = modrinth.search_page("sodium", SearchIndex::Relevance, 10, &[]).await?;
+    /// assert!(page.offset == 0);
+    /// # Ok(()) }
+    /// ```
+    pub async fn search_page(
+        &self,
+        query: &str,
+        index: SearchIndex,
+        limit: Number,
+        facets: &[Vec<Facet>],
+    ) -> Result<SearchPage> {
+        let response = self.search(query, index, 0, limit, facets).await?;
+        Ok(SearchPage::new(response, query, index, limit, facets))
+    }
+
+    /// Search for projects like [`Ferinth::search`], returning a stream of all the results.
+    ///
+    /// The results are requested `limit` at a time as the stream is polled,
+    /// and the stream ends once all `total_hits` of them have been returned.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::search::{Facet, SearchIndex};
+    /// # use futures_util::TryStreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let facets = [vec![Facet::Categories("fabric".into())]];
+    /// let mut hits = Box::pin(modrinth.search_iter("sodium", SearchIndex::Relevance, 20, &facets));
+    /// while let Some(hit) = hits.try_next().await? {
+    ///     println!("{}", hit.title);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn search_iter<'a>(
+        &'a self,
+        query: &'a str,
+        index: SearchIndex,
+        limit: Number,
+        facets: &'a [Vec<Facet>],
+    ) -> impl Stream<Item = Result<SearchHit>> + 'a {
+        // The offset of the next page, or `None` once all the results have been returned
+        stream::try_unfold(Some(0), move |offset| async move {
+            let offset = match offset {
+                Some(offset) => offset,
+                None => return Result::Ok(None),
+            };
+            let page = self.search(query, index, offset, limit, facets).await?;
+            let next = next_offset(offset, page.hits.len(), page.total_hits);
+            Ok(Some((page.hits, next)))
+        })
+        .map_ok(|hits| stream::iter(hits.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
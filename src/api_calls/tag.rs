@@ -1,4 +1,4 @@
-use crate::{request::API_URL_BASE, structures::tag::*, url_join_ext::UrlJoinExt, Ferinth, Result};
+use crate::{structures::tag::*, url_join_ext::UrlJoinExt, Ferinth, Result};
 
 impl Ferinth {
     /// List the categories, their icons, and applicable project types
@@ -12,7 +12,7 @@ impl Ferinth {
     /// # Ok(()) }
     /// ```
     pub async fn list_categories(&self) -> Result<Vec<Category>> {
-        self.get(API_URL_BASE.join_all(vec!["tag", "category"]))
+        self.get(self.base_url.join_all(vec!["tag", "category"]))
             .await
     }
 
@@ -27,7 +27,7 @@ impl Ferinth {
     /// # Ok(()) }
     /// ```
     pub async fn list_loaders(&self) -> Result<Vec<Loader>> {
-        self.get(API_URL_BASE.join_all(vec!["tag", "loader"])).await
+        self.get(self.base_url.join_all(vec!["tag", "loader"])).await
     }
 
     /// List the game versions and information about them
@@ -41,10 +41,26 @@ impl Ferinth {
     /// # Ok(()) }
     /// ```
     pub async fn list_game_versions(&self) -> Result<Vec<GameVersion>> {
-        self.get(API_URL_BASE.join_all(vec!["tag", "game_version"]))
+        self.get(self.base_url.join_all(vec!["tag", "game_version"]))
             .await
     }
 
+    /// List the game versions, or an empty list if they couldn't be fetched.
+    ///
+    /// This is for helpers that only use the game versions to improve their results,
+    /// so that they fall back to a best-effort result instead of failing.
+    /// The error is logged as a warning if the `logging` feature is enabled.
+    pub(crate) async fn list_game_versions_or_empty(&self) -> Vec<GameVersion> {
+        self.list_game_versions().await.unwrap_or_else(|_error| {
+            #[cfg(feature = "logging")]
+            log::warn!(
+                "Failed to get the game versions, falling back to ordering them by name: {}",
+                _error
+            );
+            Vec::new()
+        })
+    }
+
     /// List licenses and information about them
     ///
     /// Example:
@@ -56,7 +72,7 @@ impl Ferinth {
     /// # Ok(()) }
     /// ```
     pub async fn list_licenses(&self) -> Result<Vec<License>> {
-        self.get(API_URL_BASE.join_all(vec!["tag", "license"]))
+        self.get(self.base_url.join_all(vec!["tag", "license"]))
             .await
     }
 
@@ -71,7 +87,7 @@ impl Ferinth {
     /// # Ok(()) }
     /// ```
     pub async fn list_donation_platforms(&self) -> Result<Vec<DonationPlatform>> {
-        self.get(API_URL_BASE.join_all(vec!["tag", "donation_platform"]))
+        self.get(self.base_url.join_all(vec!["tag", "donation_platform"]))
             .await
     }
 
@@ -86,7 +102,7 @@ impl Ferinth {
     /// # Ok(()) }
     /// ```
     pub async fn list_report_types(&self) -> Result<Vec<String>> {
-        self.get(API_URL_BASE.join_all(vec!["tag", "report_type"]))
+        self.get(self.base_url.join_all(vec!["tag", "report_type"]))
             .await
     }
 }
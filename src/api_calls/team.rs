@@ -1,6 +1,6 @@
-use super::check_id_slug;
+use super::{check_id_slug, check_id_slugs};
 use crate::{
-    request::API_URL_BASE, structures::user::*, url_join_ext::UrlJoinExt, Ferinth, Result,
+    structures::user::*, url_join_ext::UrlJoinExt, Ferinth, Result,
 };
 
 impl Ferinth {
@@ -15,9 +15,13 @@ impl Ferinth {
     /// assert!(mod_menu_team.len() == 4);
     /// # Ok(()) }
     /// ```
-    pub async fn list_project_team_members(&self, project_id: &str) -> Result<Vec<TeamMember>> {
+    pub async fn list_project_team_members(
+        &self,
+        project_id: impl AsRef<str>,
+    ) -> Result<Vec<TeamMember>> {
+        let project_id = project_id.as_ref();
         check_id_slug(project_id)?;
-        self.get(API_URL_BASE.join_all(vec!["project", project_id, "members"]))
+        self.get(self.base_url.join_all(vec!["project", project_id, "members"]))
             .await
     }
 
@@ -32,9 +36,10 @@ impl Ferinth {
     /// assert!(mod_menu_team.len() == 4);
     /// # Ok(()) }
     /// ```
-    pub async fn list_team_members(&self, team_id: &str) -> Result<Vec<TeamMember>> {
+    pub async fn list_team_members(&self, team_id: impl AsRef<str>) -> Result<Vec<TeamMember>> {
+        let team_id = team_id.as_ref();
         check_id_slug(team_id)?;
-        self.get(API_URL_BASE.join_all(vec!["team", team_id, "members"]))
+        self.get(self.base_url.join_all(vec!["team", team_id, "members"]))
             .await
     }
 
@@ -55,14 +60,15 @@ impl Ferinth {
     /// modrinth.add_user("XXXXXXXX", "YYYYYYYY").await
     /// # }
     /// ```
-    pub async fn add_user(&self, team_id: &str, user_id: &str) -> Result<()> {
+    pub async fn add_user(&self, team_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Result<()> {
         #[derive(serde::Serialize)]
         struct Body<'a> {
             user_id: &'a str,
         }
 
+        let user_id = user_id.as_ref();
         self.post(
-            API_URL_BASE.join_all(vec!["team", team_id, "members"]),
+            self.base_url.join_all(vec!["team", team_id.as_ref(), "members"]),
             &Body { user_id },
         )
         .await
@@ -86,13 +92,11 @@ impl Ferinth {
     /// ```
     pub async fn list_multiple_teams_members(
         &self,
-        team_ids: &[&str],
+        team_ids: impl IntoIterator<Item = impl AsRef<str>>,
     ) -> Result<Vec<Vec<TeamMember>>> {
-        for team_id in team_ids {
-            check_id_slug(team_id)?;
-        }
+        let team_ids = check_id_slugs(team_ids)?;
         self.get_with_query(
-            API_URL_BASE.join_all(vec!["teams"]),
+            self.base_url.join_all(vec!["teams"]),
             &[("ids", serde_json::to_string(&team_ids)?)],
         )
         .await
@@ -115,8 +119,8 @@ impl Ferinth {
     /// modrinth.join_team("XXXXXXXX").await
     /// # }
     /// ```
-    pub async fn join_team(&self, team_id: &str) -> Result<()> {
-        self.post(API_URL_BASE.join_all(vec!["team", team_id, "join"]), "")
+    pub async fn join_team(&self, team_id: impl AsRef<str>) -> Result<()> {
+        self.post_no_content(self.base_url.join_all(vec!["team", team_id.as_ref(), "join"]))
             .await
     }
 
@@ -137,14 +141,19 @@ impl Ferinth {
     /// modrinth.add_user("XXXXXXXX", "YYYYYYYY").await
     /// # }
     /// ```
-    pub async fn transfer_ownership(&self, team_id: &str, user_id: &str) -> Result<()> {
+    pub async fn transfer_ownership(
+        &self,
+        team_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+    ) -> Result<()> {
         #[derive(serde::Serialize)]
         struct Body<'a> {
             user_id: &'a str,
         }
 
+        let user_id = user_id.as_ref();
         self.post(
-            API_URL_BASE.join_all(vec!["team", team_id, "owner"]),
+            self.base_url.join_all(vec!["team", team_id.as_ref(), "owner"]),
             &Body { user_id },
         )
         .await
@@ -1,10 +1,10 @@
-use super::check_id_slug;
+use super::{check_id_slug, check_id_slugs};
 use crate::{
-    request::API_URL_BASE,
     structures::{project::Project, user::*},
     url_join_ext::UrlJoinExt,
     Ferinth, Result,
 };
+use futures_util::future::{try_join, try_join3};
 
 impl Ferinth {
     /// Get user with ID `user_id`
@@ -19,9 +19,10 @@ impl Ferinth {
     /// assert!(jellysquid.role == UserRole::Developer);
     /// # Ok(()) }
     /// ```
-    pub async fn get_user(&self, user_id: &str) -> Result<User> {
+    pub async fn get_user(&self, user_id: impl AsRef<str>) -> Result<User> {
+        let user_id = user_id.as_ref();
         check_id_slug(user_id)?;
-        self.get(API_URL_BASE.join_all(vec!["user", user_id])).await
+        self.get(self.base_url.join_all(vec!["user", user_id])).await
     }
 
     /// Get the user of the current authorisation header
@@ -44,7 +45,7 @@ impl Ferinth {
     /// # Ok(()) }
     /// ```
     pub async fn get_current_user(&self) -> Result<User> {
-        self.get(API_URL_BASE.join_all(vec!["user"])).await
+        self.get(self.base_url.join_all(vec!["user"])).await
     }
 
     /// Get multiple users with IDs `user_ids`
@@ -59,13 +60,14 @@ impl Ferinth {
     /// assert!(users.len() == 2);
     /// # Ok(()) }
     /// ```
-    pub async fn get_multiple_users(&self, user_ids: &[&str]) -> Result<Vec<User>> {
-        for user_id in user_ids {
-            check_id_slug(user_id)?;
-        }
+    pub async fn get_multiple_users(
+        &self,
+        user_ids: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Vec<User>> {
+        let user_ids = check_id_slugs(user_ids)?;
         self.get_with_query(
-            API_URL_BASE.join_all(vec!["users"]),
-            &[("ids", &serde_json::to_string(user_ids)?)],
+            self.base_url.join_all(vec!["users"]),
+            &[("ids", &serde_json::to_string(&user_ids)?)],
         )
         .await
     }
@@ -81,12 +83,63 @@ impl Ferinth {
     /// assert!(jellysquid_projects.len() == 3);
     /// # Ok(()) }
     /// ```
-    pub async fn list_projects(&self, user_id: &str) -> Result<Vec<Project>> {
+    pub async fn list_projects(&self, user_id: impl AsRef<str>) -> Result<Vec<Project>> {
+        let user_id = user_id.as_ref();
         check_id_slug(user_id)?;
-        self.get(API_URL_BASE.join_all(vec!["user", user_id, "projects"]))
+        self.get(self.base_url.join_all(vec!["user", user_id, "projects"]))
             .await
     }
 
+    /// Get the user with ID or username `user_id` along with their projects, concurrently.
+    ///
+    /// If the user is the authenticated user, their notifications and followed projects are fetched too.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let jellysquid = modrinth.get_user_full("TEZXhE2U").await?;
+    /// assert!(jellysquid.projects.len() == 3);
+    /// // We are not authenticated as this user
+    /// assert!(jellysquid.notifications.is_none());
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_user_full(&self, user_id: impl AsRef<str>) -> Result<UserProfile> {
+        let user_id = user_id.as_ref();
+        let current_user = async {
+            match self.authorisation {
+                Some(_) => self.get_current_user().await.map(Some),
+                None => Ok(None),
+            }
+        };
+        let (user, projects, current_user) = try_join3(
+            self.get_user(user_id),
+            self.list_projects(user_id),
+            current_user,
+        )
+        .await?;
+
+        let (notifications, followed_projects) = match current_user {
+            Some(current_user) if current_user.id == user.id => {
+                let (notifications, followed_projects) = try_join(
+                    self.get_notifications(&user.id),
+                    self.followed_projects(&user.id),
+                )
+                .await?;
+                (Some(notifications), Some(followed_projects))
+            }
+            _ => (None, None),
+        };
+
+        Ok(UserProfile {
+            user,
+            projects,
+            notifications,
+            followed_projects,
+        })
+    }
+
     /// Get a list of notifications the user has received
     ///
     /// REQUIRES AUTHENTICATION!
@@ -105,9 +158,96 @@ impl Ferinth {
     /// modrinth.get_notifications(&current_user.id).await?;
     /// # Ok(()) }
     /// ```
-    pub async fn get_notifications(&self, user_id: &str) -> Result<Vec<Notification>> {
+    pub async fn get_notifications(&self, user_id: impl AsRef<str>) -> Result<Vec<Notification>> {
+        let user_id = user_id.as_ref();
         check_id_slug(user_id)?;
-        self.get(API_URL_BASE.join_all(vec!["user", user_id, "notifications"]))
+        self.get(self.base_url.join_all(vec!["user", user_id, "notifications"]))
+            .await
+    }
+
+    /// Mark the notification with ID `notification_id` as read
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// let current_user = modrinth.get_current_user().await?;
+    /// let notifications = modrinth.get_notifications(&current_user.id).await?;
+    /// if let Some(notification) = notifications.first() {
+    ///     modrinth.mark_notification_read(&notification.id).await?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn mark_notification_read(&self, notification_id: impl AsRef<str>) -> Result<()> {
+        let notification_id = notification_id.as_ref();
+        check_id_slug(notification_id)?;
+        self.patch_no_content(self.base_url.join_all(vec!["notification", notification_id]))
+            .await
+    }
+
+    /// Mark the notifications with IDs `notification_ids` as read
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// let current_user = modrinth.get_current_user().await?;
+    /// let notifications = modrinth.get_notifications(&current_user.id).await?;
+    /// modrinth.mark_notifications_read(notifications.iter().map(|notification| &notification.id)).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn mark_notifications_read(
+        &self,
+        notification_ids: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<()> {
+        let notification_ids = check_id_slugs(notification_ids)?;
+        let mut url = self.base_url.join_all(vec!["notifications"]);
+        url.query_pairs_mut()
+            .append_pair("ids", &serde_json::to_string(&notification_ids)?);
+        self.patch_no_content(url).await
+    }
+
+    /// Delete the notification with ID `notification_id`
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```ignore
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// let current_user = modrinth.get_current_user().await?;
+    /// let notifications = modrinth.get_notifications(&current_user.id).await?;
+    /// if let Some(notification) = notifications.first() {
+    ///     modrinth.delete_notification(&notification.id).await?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn delete_notification(&self, notification_id: impl AsRef<str>) -> Result<()> {
+        let notification_id = notification_id.as_ref();
+        check_id_slug(notification_id)?;
+        self.delete(self.base_url.join_all(vec!["notification", notification_id]))
             .await
     }
 
@@ -129,9 +269,10 @@ impl Ferinth {
     /// modrinth.followed_projects(&current_user.id).await?;
     /// # Ok(()) }
     /// ```
-    pub async fn followed_projects(&self, user_id: &str) -> Result<Vec<Project>> {
+    pub async fn followed_projects(&self, user_id: impl AsRef<str>) -> Result<Vec<Project>> {
+        let user_id = user_id.as_ref();
         check_id_slug(user_id)?;
-        self.get(API_URL_BASE.join_all(vec!["user", user_id, "follows"]))
+        self.get(self.base_url.join_all(vec!["user", user_id, "follows"]))
             .await
     }
 
@@ -149,12 +290,12 @@ impl Ferinth {
     /// #     None,
     /// #     Some(env!("MODRINTH_TOKEN")),
     /// # )?;
-    /// let current_user = modrinth.submit_report(
-    ///     ???,
-    ///     "XXXXXXXX",
+    /// let report = modrinth.submit_report(
+    ///     "spam".into(),
+    ///     "XXXXXXXX".into(),
     ///     ferinth::structures::user::ReportItemType::User,
-    ///     "This is an example report",
-    /// ).await?
+    ///     "This is an example report".into(),
+    /// ).await?;
     /// # Ok(()) }
     /// ```
     pub async fn submit_report(
@@ -163,10 +304,10 @@ impl Ferinth {
         item_id: String,
         item_type: ReportItemType,
         body: String,
-    ) -> Result<Vec<Project>> {
+    ) -> Result<Report> {
         check_id_slug(&item_id)?;
         self.post(
-            API_URL_BASE.join_all(vec!["report"]),
+            self.base_url.join_all(vec!["report"]),
             &ReportSubmission {
                 report_type,
                 item_id,
@@ -176,4 +317,141 @@ impl Ferinth {
         )
         .await
     }
+
+    /// Get the reports the current user has submitted
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// let reports = modrinth.get_reports().await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_reports(&self) -> Result<Vec<Report>> {
+        self.get(self.base_url.join_all(vec!["report"])).await
+    }
+
+    /// Get the report with ID `report_id`
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// if let Some(report) = modrinth.get_reports().await?.first() {
+    ///     assert!(modrinth.get_report(&report.id).await?.body == report.body);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_report(&self, report_id: impl AsRef<str>) -> Result<Report> {
+        let report_id = report_id.as_ref();
+        check_id_slug(report_id)?;
+        self.get(self.base_url.join_all(vec!["report", report_id]))
+            .await
+    }
+
+    /// Change the extended explanation of the report with ID `report_id` to `body`
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```ignore
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// modrinth.modify_report("XXXXXXXX", "This is an updated example report").await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn modify_report(&self, report_id: impl AsRef<str>, body: &str) -> Result<()> {
+        #[derive(serde::Serialize)]
+        struct Body<'a> {
+            body: &'a str,
+        }
+
+        let report_id = report_id.as_ref();
+        check_id_slug(report_id)?;
+        self.patch(
+            self.base_url.join_all(vec!["report", report_id]),
+            &Body { body },
+        )
+        .await
+    }
+
+    /// Set the payout settings of the user with ID `user_id`,
+    /// payouts will be sent to `address` of type `wallet_type` using `wallet`.
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```ignore
+    /// # use ferinth::structures::user::{PayoutWallet, PayoutWalletType};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// let current_user = modrinth.get_current_user().await?;
+    /// modrinth.set_payout_settings(
+    ///     &current_user.id,
+    ///     PayoutWallet::PayPal,
+    ///     PayoutWalletType::Email,
+    ///     "user@example.com",
+    /// ).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn set_payout_settings(
+        &self,
+        user_id: impl AsRef<str>,
+        wallet: PayoutWallet,
+        wallet_type: PayoutWalletType,
+        address: &str,
+    ) -> Result<()> {
+        #[derive(serde::Serialize)]
+        struct PayoutSettings<'a> {
+            payout_wallet: PayoutWallet,
+            payout_wallet_type: PayoutWalletType,
+            payout_address: &'a str,
+        }
+        #[derive(serde::Serialize)]
+        struct Body<'a> {
+            payout_data: PayoutSettings<'a>,
+        }
+
+        let user_id = user_id.as_ref();
+        check_id_slug(user_id)?;
+        self.patch(
+            self.base_url.join_all(vec!["user", user_id]),
+            &Body {
+                payout_data: PayoutSettings {
+                    payout_wallet: wallet,
+                    payout_wallet_type: wallet_type,
+                    payout_address: address,
+                },
+            },
+        )
+        .await
+    }
 }
@@ -1,11 +1,21 @@
-use super::check_id_slug;
+use super::{check_id_slug, check_id_slugs};
 use crate::{
-    request::API_URL_BASE, structures::version::*, url_join_ext::UrlJoinExt, Ferinth,
-    Result,
+    request::FormPart,
+    structures::{tag::GameVersion, version::*, ID},
+    url_join_ext::UrlJoinExt,
+    Error, Ferinth, Result,
 };
+use futures_util::{stream, StreamExt};
+use std::collections::{HashMap, HashSet};
+
+/// The maximum number of projects requested at once by [`Ferinth::latest_versions_for_projects`]
+const PROJECTS_CONCURRENCY: usize = 8;
 
 impl Ferinth {
-    /// Get the versions of project with ID `project_id`
+    /// Get the public versions of project with ID `project_id`
+    ///
+    /// Drafts and unlisted versions are excluded even if you are authorised to see them,
+    /// use [`Ferinth::list_all_versions`] to include them.
     ///
     /// Example:
     /// ```rust
@@ -16,13 +26,59 @@ impl Ferinth {
     /// assert!(sodium_versions[0].project_id == "AANobbMI");
     /// # Ok(()) }
     /// ```
-    pub async fn list_versions(&self, project_id: &str) -> Result<Vec<Version>> {
+    pub async fn list_versions(&self, project_id: impl AsRef<str>) -> Result<Vec<Version>> {
+        let mut versions = self.list_all_versions(project_id).await?;
+        versions.retain(Version::is_public);
+        Ok(versions)
+    }
+
+    /// Get the public versions of project with ID `project_id`, sorted from most to least downloaded
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_versions = modrinth.list_versions_by_downloads("AANobbMI").await?;
+    /// assert!(sodium_versions
+    ///     .windows(2)
+    ///     .all(|pair| pair[0].downloads >= pair[1].downloads));
+    /// # Ok(()) }
+    /// ```
+    pub async fn list_versions_by_downloads(
+        &self,
+        project_id: impl AsRef<str>,
+    ) -> Result<Vec<Version>> {
+        let mut versions = self.list_versions(project_id).await?;
+        Version::sort_by_downloads(&mut versions);
+        Ok(versions)
+    }
+
+    /// Get all the versions of project with ID `project_id`, including drafts and unlisted versions
+    ///
+    /// REQUIRES AUTHENTICATION! Without it, or if you aren't a member of the project, only the public versions are returned.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// let versions = modrinth.list_all_versions(env!("TEST_PROJECT_ID")).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn list_all_versions(&self, project_id: impl AsRef<str>) -> Result<Vec<Version>> {
+        let project_id = project_id.as_ref();
         check_id_slug(project_id)?;
-        self.get(API_URL_BASE.join_all(vec!["project", project_id, "version"]))
+        self.get(self.base_url.join_all(vec!["project", project_id, "version"]))
             .await
     }
 
-    /// Get the versions of project with ID `project_id` with filters
+    /// Get the public versions of project with ID `project_id` with filters
     ///
     /// `loaders`: The types of loaders to filter for
     /// `game_versions`: The game versions to filter for
@@ -30,20 +86,22 @@ impl Ferinth {
     ///
     /// Example:
     /// ```rust
+    /// # use ferinth::structures::version::ModLoader;
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), ferinth::Error> {
     /// # let modrinth = ferinth::Ferinth::default();
-    /// let sodium_forge_versions = modrinth.list_versions_filtered("AANobbMI", Some(&["forge"]), None, None).await?;
+    /// let sodium_forge_versions = modrinth.list_versions_filtered("AANobbMI", Some(&[ModLoader::Forge]), None, None).await?;
     /// assert!(sodium_forge_versions.is_empty());
     /// # Ok(()) }
     /// ```
     pub async fn list_versions_filtered(
         &self,
-        project_id: &str,
-        loaders: Option<&[&str]>,
+        project_id: impl AsRef<str>,
+        loaders: Option<&[ModLoader]>,
         game_versions: Option<&[&str]>,
         featured: Option<bool>,
     ) -> Result<Vec<Version>> {
+        let project_id = project_id.as_ref();
         check_id_slug(project_id)?;
         let mut query = Vec::new();
         if let Some(loaders) = loaders {
@@ -59,11 +117,203 @@ impl Ferinth {
             .into_iter()
             .map(|this| (this.0, this.1))
             .collect::<Vec<_>>();
-        self.get_with_query(
-            API_URL_BASE.join_all(vec!["project", project_id, "version"]),
-            &query,
-        )
-        .await
+        let mut versions: Vec<Version> = self
+            .get_with_query(
+                self.base_url.join_all(vec!["project", project_id, "version"]),
+                &query,
+            )
+            .await?;
+        versions.retain(Version::is_public);
+        Ok(versions)
+    }
+
+    /// Resolve the game version `minimum` to it and all the newer release versions,
+    /// for filtering versions by game version with [`Ferinth::list_versions_filtered`].
+    /// If `include_snapshots` is set, the snapshots since the last release older than `minimum` are included too.
+    ///
+    /// See [`GameVersion::resolve_minimum`] for details.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let game_versions = modrinth.resolve_game_versions("1.20", false).await?;
+    /// assert!(game_versions.contains(&"1.20.1".to_string()));
+    /// assert!(!game_versions.contains(&"1.19.4".to_string()));
+    /// # Ok(()) }
+    /// ```
+    pub async fn resolve_game_versions(
+        &self,
+        minimum: &str,
+        include_snapshots: bool,
+    ) -> Result<Vec<String>> {
+        GameVersion::resolve_minimum(&self.list_game_versions().await?, minimum, include_snapshots)
+    }
+
+    /// Get the newest version of project with ID `project_id` that supports `loader` and `game_version`,
+    /// and optionally is on the release `channel`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::version::{ModLoader, VersionType};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_version = modrinth
+    ///     .get_version_for("AANobbMI", ModLoader::Fabric, "1.20.1", Some(VersionType::Release))
+    ///     .await?
+    ///     .unwrap();
+    /// assert!(sodium_version.game_versions.contains(&"1.20.1".to_string()));
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_version_for(
+        &self,
+        project_id: impl AsRef<str>,
+        loader: ModLoader,
+        game_version: &str,
+        channel: Option<VersionType>,
+    ) -> Result<Option<Version>> {
+        Ok(self
+            .list_versions_filtered(
+                project_id,
+                Some(&[loader]),
+                Some(&[game_version]),
+                None,
+            )
+            .await?
+            .into_iter()
+            .filter(|version| {
+                channel
+                    .as_ref()
+                    .map_or(true, |channel| channel == &version.version_type)
+            })
+            .max_by_key(|version| version.date_published))
+    }
+
+    /// Get the newest version of each of the projects with IDs `project_ids` that supports `loader` and `game_version`,
+    /// mapped to the project ID they were requested with.
+    /// Projects without a compatible version are not included.
+    ///
+    /// The projects are requested concurrently, up to 8 at a time.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::version::ModLoader;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let latest = modrinth
+    ///     .latest_versions_for_projects(&["AANobbMI", "P7dR8mSH"], ModLoader::Fabric, "1.20.1")
+    ///     .await?;
+    /// assert!(latest.contains_key("P7dR8mSH"));
+    /// # Ok(()) }
+    /// ```
+    pub async fn latest_versions_for_projects(
+        &self,
+        project_ids: impl IntoIterator<Item = impl AsRef<str>>,
+        loader: ModLoader,
+        game_version: &str,
+    ) -> Result<HashMap<ID, Version>> {
+        let project_ids = check_id_slugs(project_ids)?;
+        let loader = &loader;
+        let results: Vec<_> = stream::iter(project_ids.iter().map(|project_id| async move {
+            self.get_version_for(project_id, loader.clone(), game_version, None)
+                .await
+                .map(|version| version.map(|version| (project_id.clone(), version)))
+        }))
+        .buffer_unordered(PROJECTS_CONCURRENCY)
+        .collect()
+        .await;
+        let mut latest = HashMap::new();
+        for result in results {
+            latest.extend(result?);
+        }
+        Ok(latest)
+    }
+
+    /// Resolve the required dependencies of `version` transitively, returning each dependency's version once,
+    /// with dependencies before the versions that depend on them.
+    ///
+    /// Dependencies on a specific version use that version, otherwise the newest version of the project
+    /// that supports `version`'s loaders and game versions is used.
+    /// Dependencies without a compatible version are skipped.
+    ///
+    /// If the dependencies form a cycle, [`Error::DependencyCycle`] is returned with the project IDs in the cycle.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let version = modrinth.get_version("sxWTUZpD").await?;
+    /// let dependencies = modrinth.resolve_dependencies(&version).await?;
+    /// assert!(dependencies.iter().all(|dependency| dependency.project_id != version.project_id));
+    /// # Ok(()) }
+    /// ```
+    pub async fn resolve_dependencies(&self, version: &Version) -> Result<Vec<Version>> {
+        let game_versions: Vec<_> = version.game_versions.iter().map(String::as_str).collect();
+        let mut resolved = Vec::new();
+        let mut seen = HashSet::new();
+        // The versions on the path from `version` to the one being resolved, and how many of their dependencies have been checked
+        let mut stack = vec![(version.clone(), 0)];
+
+        while let Some((current, checked)) = stack.last_mut() {
+            let dependency = match current
+                .dependencies
+                .iter()
+                .enumerate()
+                .skip(*checked)
+                .find(|(_, dependency)| DependencyType::Required == dependency.dependency_type)
+            {
+                Some((index, dependency)) => {
+                    *checked = index + 1;
+                    dependency.clone()
+                }
+                None => {
+                    let (current, _) = stack.pop().unwrap();
+                    seen.insert(current.project_id.clone());
+                    if !stack.is_empty() {
+                        resolved.push(current);
+                    }
+                    continue;
+                }
+            };
+
+            let dependency = match (&dependency.version_id, &dependency.project_id) {
+                (Some(version_id), _) => Some(self.get_version(version_id).await?),
+                (None, Some(project_id)) => self
+                    .list_versions_filtered(
+                        project_id,
+                        Some(&version.loaders),
+                        Some(&game_versions),
+                        None,
+                    )
+                    .await?
+                    .into_iter()
+                    .max_by_key(|version| version.date_published),
+                (None, None) => None,
+            };
+            let dependency = match dependency {
+                Some(dependency) => dependency,
+                None => continue,
+            };
+            if let Some(start) = stack
+                .iter()
+                .position(|(version, _)| version.project_id == dependency.project_id)
+            {
+                let mut path: Vec<_> = stack[start..]
+                    .iter()
+                    .map(|(version, _)| version.project_id.clone())
+                    .collect();
+                path.push(dependency.project_id);
+                return Err(Error::DependencyCycle { path });
+            }
+            if !seen.contains(&dependency.project_id) {
+                stack.push((dependency, 0));
+            }
+        }
+        Ok(resolved)
     }
 
     /// Get version with ID `version_id`
@@ -77,14 +327,60 @@ impl Ferinth {
     /// assert!(sodium_version.project_id == "AANobbMI");
     /// # Ok(()) }
     /// ```
-    pub async fn get_version(&self, version_id: &str) -> Result<Version> {
+    pub async fn get_version(&self, version_id: impl AsRef<str>) -> Result<Version> {
+        let version_id = version_id.as_ref();
         check_id_slug(version_id)?;
-        self.get(API_URL_BASE.join_all(vec!["version", version_id]))
+        self.get(self.base_url.join_all(vec!["version", version_id]))
+            .await
+    }
+
+    /// Get the latest state of `version` from the API, for example after editing it
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_version = modrinth.get_version("xuWxRZPd").await?;
+    /// let refreshed = modrinth.refresh_version(&sodium_version).await?;
+    /// assert!(refreshed.id == sodium_version.id);
+    /// # Ok(()) }
+    /// ```
+    pub async fn refresh_version(&self, version: &Version) -> Result<Version> {
+        self.get_version(&version.id).await
+    }
+
+    /// Delete the version with ID `version_id`, along with all of its files.
+    ///
+    /// WARNING: THIS IS IRREVERSIBLE! The version can't be recovered once it's deleted.
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```ignore
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// modrinth.delete_version("XXXXXXXX").await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn delete_version(&self, version_id: impl AsRef<str>) -> Result<()> {
+        let version_id = version_id.as_ref();
+        check_id_slug(version_id)?;
+        self.delete(self.base_url.join_all(vec!["version", version_id]))
             .await
     }
 
     /// Get multiple versions with IDs `version_ids`
     ///
+    /// If all the versions belong to the same project and you need a large portion of its versions,
+    /// [`Ferinth::get_multiple_versions_from_project`] might be cheaper as it makes a single `list_versions` request.
+    ///
     /// Example:
     /// ```rust
     /// # #[tokio::main]
@@ -99,14 +395,122 @@ impl Ferinth {
     /// }
     /// # Ok(()) }
     /// ```
-    pub async fn get_multiple_versions(&self, version_ids: &[&str]) -> Result<Vec<Version>> {
-        for versions_id in version_ids {
-            check_id_slug(versions_id)?;
-        }
+    pub async fn get_multiple_versions(
+        &self,
+        version_ids: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Vec<Version>> {
+        let version_ids = check_id_slugs(version_ids)?;
         self.get_with_query(
-            API_URL_BASE.join_all(vec!["versions"]),
-            &[("ids", &serde_json::to_string(version_ids)?)],
+            self.base_url.join_all(vec!["versions"]),
+            &[("ids", &serde_json::to_string(&version_ids)?)],
         )
         .await
     }
+
+    /// Get multiple versions with IDs `version_ids`, which all belong to the project with ID `project_id`.
+    ///
+    /// Rather than using the bulk versions endpoint, this lists all the versions of the project
+    /// and filters them locally. This is cheaper when you want a large portion of a project's versions,
+    /// but wasteful if the project has many versions and you only want a few of them,
+    /// in which case [`Ferinth::get_multiple_versions`] should be used instead.
+    ///
+    /// The versions are returned in the order that [`Ferinth::list_all_versions`] returns them,
+    /// and IDs that don't belong to the project are ignored.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let versions = modrinth.get_multiple_versions_from_project("of7wIinq", &[
+    ///     "sxWTUZpD",
+    ///     "mgPpe4NY",
+    /// ]).await?;
+    /// assert!(versions.len() == 2);
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_multiple_versions_from_project(
+        &self,
+        project_id: impl AsRef<str>,
+        version_ids: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Vec<Version>> {
+        let version_ids = check_id_slugs(version_ids)?;
+        Ok(self
+            .list_all_versions(project_id)
+            .await?
+            .into_iter()
+            .filter(|version| version_ids.contains(&version.id))
+            .collect())
+    }
+
+    /// Create a version with the metadata `data`, uploading `files`, which are pairs of file names and contents.
+    /// Set `data.primary_file` to the name of the primary file.
+    ///
+    /// REQUIRES AUTHENTICATION!
+    ///
+    /// Example:
+    /// ```ignore
+    /// # use ferinth::structures::version::{ModLoader, VersionCreate, VersionType};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::new(
+    /// #     env!("CARGO_CRATE_NAME"),
+    /// #     Some(env!("CARGO_PKG_VERSION")),
+    /// #     None,
+    /// #     Some(env!("MODRINTH_TOKEN")),
+    /// # )?;
+    /// let version = modrinth.create_version(
+    ///     VersionCreate {
+    ///         name: "Example version".into(),
+    ///         version_number: "1.0.0".into(),
+    ///         changelog: None,
+    ///         dependencies: Vec::new(),
+    ///         game_versions: vec!["1.19.2".into()],
+    ///         version_type: VersionType::Release,
+    ///         loaders: vec![ModLoader::Fabric],
+    ///         featured: false,
+    ///         status: None,
+    ///         project_id: env!("TEST_PROJECT_ID").into(),
+    ///         primary_file: Some("example.jar".into()),
+    ///     },
+    ///     &[("example.jar".into(), std::fs::read("example.jar")?)],
+    /// ).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn create_version(
+        &self,
+        data: VersionCreate,
+        files: &[(String, Vec<u8>)],
+    ) -> Result<Version> {
+        #[derive(serde::Serialize)]
+        struct Body<'a> {
+            #[serde(flatten)]
+            data: &'a VersionCreate,
+            /// The names of the parts containing the files
+            file_parts: Vec<&'a str>,
+        }
+
+        if files.is_empty() {
+            return Err(Error::NoFiles);
+        }
+        check_id_slug(&data.project_id)?;
+        let body = serde_json::to_vec(&Body {
+            data: &data,
+            file_parts: files.iter().map(|(name, _)| name.as_str()).collect(),
+        })?;
+        let mut parts = vec![FormPart {
+            name: "data",
+            filename: None,
+            content_type: "application/json",
+            content: &body,
+        }];
+        parts.extend(files.iter().map(|(name, contents)| FormPart {
+            name,
+            filename: Some(name),
+            content_type: "application/octet-stream",
+            content: contents,
+        }));
+        self.post_multipart(self.base_url.join_all(vec!["version"]), &parts)
+            .await
+    }
 }
@@ -1,96 +1,236 @@
-use super::check_sha1_hash;
+use super::check_hash;
 use crate::{
-    request::API_URL_BASE, structures::version::*, url_join_ext::UrlJoinExt, Ferinth,
-    Result,
+    structures::version::*, url_join_ext::UrlJoinExt, Ferinth, Result,
 };
 use std::collections::HashMap;
 
 impl Ferinth {
-    /// Get the version of a version file with hash `file_hash`. Only supports SHA1 hashes for now
+    /// Get the version of a version file with hash `file_hash`, computed with `algorithm`.
+    ///
+    /// Hashes must be lowercase hex, 40 characters long for SHA1 and 128 for SHA512.
     ///
     /// Example:
     /// ```rust
+    /// # use ferinth::structures::version::HashAlgorithm;
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), ferinth::Error> {
     /// # let modrinth = ferinth::Ferinth::default();
     /// // A mod file has the hash `795d4c12bffdb1b21eed5ff87c07ce5ca3c0dcbf`, so we can get the version it belongs to
-    /// let sodium_version = modrinth.get_version_from_hash("795d4c12bffdb1b21eed5ff87c07ce5ca3c0dcbf").await?;
+    /// let sodium_version = modrinth
+    ///     .get_version_from_hash("795d4c12bffdb1b21eed5ff87c07ce5ca3c0dcbf", HashAlgorithm::SHA1)
+    ///     .await?;
     /// assert!(sodium_version.project_id == "AANobbMI");
+    ///
+    /// // Malformed hashes are rejected without making a request
+    /// assert!(matches!(
+    ///     modrinth.get_version_from_hash("795d4c12", HashAlgorithm::SHA1).await,
+    ///     Err(ferinth::Error::NotSHA1),
+    /// ));
     /// # Ok(()) }
     /// ```
-    pub async fn get_version_from_hash(&self, file_hash: &str) -> Result<Version> {
-        check_sha1_hash(file_hash)?;
-        self.get(API_URL_BASE.join_all(vec!["version_file", file_hash]))
-            .await
+    pub async fn get_version_from_hash(
+        &self,
+        file_hash: &str,
+        algorithm: HashAlgorithm,
+    ) -> Result<Version> {
+        check_hash(file_hash, &algorithm)?;
+        self.get_with_query(
+            self.base_url.join_all(vec!["version_file", file_hash]),
+            &[("algorithm", algorithm.as_str())],
+        )
+        .await
     }
 
-    /// Get the versions of version files with hashes `file_hashes`. Only supports SHA1 hashes for now
+    /// Check whether a version file with hash `file_hash`, computed with `algorithm`, exists on Modrinth.
+    ///
+    /// This uses a HEAD request, so it is cheaper than [`Ferinth::get_version_from_hash`]
+    /// when the version itself isn't needed.
     ///
     /// Example:
     /// ```rust
+    /// # use ferinth::structures::version::HashAlgorithm;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// assert!(modrinth
+    ///     .version_file_exists("795d4c12bffdb1b21eed5ff87c07ce5ca3c0dcbf", HashAlgorithm::SHA1)
+    ///     .await?);
+    /// assert!(!modrinth
+    ///     .version_file_exists("0000000000000000000000000000000000000000", HashAlgorithm::SHA1)
+    ///     .await?);
+    /// # Ok(()) }
+    /// ```
+    pub async fn version_file_exists(
+        &self,
+        file_hash: &str,
+        algorithm: HashAlgorithm,
+    ) -> Result<bool> {
+        check_hash(file_hash, &algorithm)?;
+        self.exists_with_query(
+            self.base_url.join_all(vec!["version_file", file_hash]),
+            &[("algorithm", algorithm.as_str())],
+        )
+        .await
+    }
+
+    /// Get the versions of version files with hashes `file_hashes`, computed with `algorithm`.
+    ///
+    /// The map is keyed by the lowercased hashes as returned by the server.
+    /// Hashes that don't belong to any version on Modrinth will not be present.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::version::HashAlgorithm;
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), ferinth::Error> {
     /// # let modrinth = ferinth::Ferinth::default();
     /// let sodium_hash = "795d4c12bffdb1b21eed5ff87c07ce5ca3c0dcbf";
     /// let snwylvspls_hash = "994ee99d172a5950a51ec2d08c158d270722d871";
-    /// let versions = modrinth.get_versions_from_hashes(vec![
-    ///     sodium_hash.into(),
-    ///     snwylvspls_hash.into()
-    /// ]).await?;
+    /// let unknown_hash = "0000000000000000000000000000000000000000";
+    /// let versions = modrinth.get_versions_from_hashes(
+    ///     &[sodium_hash, snwylvspls_hash, unknown_hash],
+    ///     HashAlgorithm::SHA1,
+    /// ).await?;
     /// assert!(versions[sodium_hash].project_id == "AANobbMI");
     /// assert!(versions[snwylvspls_hash].project_id == "of7wIinq");
+    /// assert!(!versions.contains_key(unknown_hash));
     /// # Ok(()) }
     /// ```
     pub async fn get_versions_from_hashes(
         &self,
-        file_hashes: Vec<String>,
+        file_hashes: &[&str],
+        algorithm: HashAlgorithm,
     ) -> Result<HashMap<String, Version>> {
-        for file_hash in &file_hashes {
-            check_sha1_hash(file_hash)?;
+        for file_hash in file_hashes {
+            check_hash(file_hash, &algorithm)?;
         }
         self.post(
-            API_URL_BASE.join_all(vec!["version_files"]),
+            self.base_url.join_all(vec!["version_files"]),
             &HashesBody {
-                hashes: file_hashes,
-                algorithm: HashAlgorithm::SHA1,
+                hashes: file_hashes.iter().map(|&file_hash| file_hash.into()).collect(),
+                algorithm,
             },
         )
         .await
     }
 
-    /// Get the latest version of the given `file_hash` based on some `filters`
-    pub async fn latest_version_from_hash(
+    /// Get the newest version of the project that the version file with hash `file_hash`, computed with `algorithm`, belongs to,
+    /// which supports any of `loaders` and any of `game_versions`.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::version::{HashAlgorithm, ModLoader};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let update = modrinth.get_version_update(
+    ///     "795d4c12bffdb1b21eed5ff87c07ce5ca3c0dcbf",
+    ///     HashAlgorithm::SHA1,
+    ///     &[ModLoader::Fabric],
+    ///     &["1.19.2"],
+    /// ).await?;
+    /// assert!(update.project_id == "AANobbMI");
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_version_update(
         &self,
         file_hash: &str,
-        filters: &LatestVersionBody,
+        algorithm: HashAlgorithm,
+        loaders: &[ModLoader],
+        game_versions: &[&str],
     ) -> Result<Version> {
-        check_sha1_hash(file_hash)?;
+        check_hash(file_hash, &algorithm)?;
         self.post_with_query(
-            API_URL_BASE.join_all(vec!["version_file", file_hash, "update"]),
-            filters,
-            &[("algorithm", &serde_json::to_string(&HashAlgorithm::SHA1)?)],
+            self.base_url.join_all(vec!["version_file", file_hash, "update"]),
+            &LatestVersionBody {
+                loaders: loaders.to_vec(),
+                game_versions: game_versions.iter().map(|&version| version.into()).collect(),
+            },
+            &[("algorithm", algorithm.as_str())],
         )
         .await
     }
 
-    /// Get the latest versions of the given `file_hashes` based on some `filters`
-    pub async fn latest_versions_from_hashes(
+    /// Get the newest versions of the projects that the version files with hashes `file_hashes`, computed with `algorithm`, belong to,
+    /// which support any of `loaders` and any of `game_versions`.
+    ///
+    /// The map is keyed by the lowercased hashes as returned by the server.
+    /// Hashes that don't belong to any version on Modrinth, or that have no matching newer version, will not be present.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::version::{HashAlgorithm, ModLoader};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_hash = "795d4c12bffdb1b21eed5ff87c07ce5ca3c0dcbf";
+    /// let updates = modrinth.get_latest_versions_from_hashes(
+    ///     &[sodium_hash],
+    ///     HashAlgorithm::SHA1,
+    ///     &[ModLoader::Fabric],
+    ///     &["1.19.2"],
+    /// ).await?;
+    /// assert!(updates[sodium_hash].project_id == "AANobbMI");
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_latest_versions_from_hashes(
         &self,
-        file_hashes: Vec<String>,
-        filters: LatestVersionBody,
-    ) -> Result<Vec<Version>> {
-        for file_hash in &file_hashes {
-            check_sha1_hash(file_hash)?;
+        file_hashes: &[&str],
+        algorithm: HashAlgorithm,
+        loaders: &[ModLoader],
+        game_versions: &[&str],
+    ) -> Result<HashMap<String, Version>> {
+        for file_hash in file_hashes {
+            check_hash(file_hash, &algorithm)?;
         }
         self.post(
-            API_URL_BASE.join_all(vec!["version_files", "update"]),
+            self.base_url.join_all(vec!["version_files", "update"]),
             &LatestVersionsBody {
-                hashes: file_hashes,
-                algorithm: HashAlgorithm::SHA1,
-                loaders: filters.loaders,
-                game_versions: filters.game_versions,
+                hashes: file_hashes.iter().map(|&file_hash| file_hash.into()).collect(),
+                algorithm,
+                loaders: loaders.to_vec(),
+                game_versions: game_versions.iter().map(|&version| version.into()).collect(),
             },
         )
         .await
     }
+    /// Get the newest version of the project that the version file with SHA1 hash `file_hash` belongs to,
+    /// which supports any of the `filters`' loaders and game versions
+    #[deprecated = "Use `get_version_update` instead"]
+    pub async fn latest_version_from_hash(
+        &self,
+        file_hash: &str,
+        filters: &LatestVersionBody,
+    ) -> Result<Version> {
+        let game_versions: Vec<_> = filters.game_versions.iter().map(String::as_str).collect();
+        self.get_version_update(
+            file_hash,
+            HashAlgorithm::SHA1,
+            &filters.loaders,
+            &game_versions,
+        )
+        .await
+    }
+
+    /// Get the newest versions of the projects that the version files with SHA1 hashes `file_hashes` belong to,
+    /// which support any of the `filters`' loaders and game versions
+    #[deprecated = "Use `get_latest_versions_from_hashes` instead, which returns which hash each version is for"]
+    pub async fn latest_versions_from_hashes(
+        &self,
+        file_hashes: Vec<String>,
+        filters: LatestVersionBody,
+    ) -> Result<Vec<Version>> {
+        let file_hashes: Vec<_> = file_hashes.iter().map(String::as_str).collect();
+        let game_versions: Vec<_> = filters.game_versions.iter().map(String::as_str).collect();
+        Ok(self
+            .get_latest_versions_from_hashes(
+                &file_hashes,
+                HashAlgorithm::SHA1,
+                &filters.loaders,
+                &game_versions,
+            )
+            .await?
+            .into_values()
+            .collect())
+    }
 }
@@ -0,0 +1,179 @@
+use crate::{
+    api_calls::check_id_slug,
+    structures::{project::Project, version::Version},
+    Error, Ferinth, Result,
+};
+use futures_channel::oneshot;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// The result of a batched request, `None` if the bulk request didn't include the item.
+/// The bulk request's error is shared between all the requests in the batch.
+type BatchResult<T> = std::result::Result<Option<T>, Arc<Error>>;
+
+/// The requests waiting to be sent in the next bulk request, along with where to send their results
+type Pending<T> = Vec<(String, oneshot::Sender<BatchResult<T>>)>;
+
+#[derive(Debug)]
+struct Batch<T>(Mutex<Option<Pending<T>>>);
+
+impl<T> Default for Batch<T> {
+    fn default() -> Self {
+        Self(Mutex::new(None))
+    }
+}
+
+impl<T> Batch<T> {
+    /// Add `id` to the pending batch, returning where its result will be sent,
+    /// and whether it started a new batch and so is responsible for flushing it
+    fn join(&self, id: &str) -> (oneshot::Receiver<BatchResult<T>>, bool) {
+        let (sender, receiver) = oneshot::channel();
+        let mut pending = self.0.lock().unwrap();
+        let leader = pending.is_none();
+        pending
+            .get_or_insert_with(Vec::new)
+            .push((id.into(), sender));
+        (receiver, leader)
+    }
+}
+
+/// Clears the pending batch when dropped before it is taken, so that a cancelled leader
+/// doesn't leave the other requests in the batch waiting (they will make their own requests instead)
+struct FlushGuard<'a, T> {
+    batch: &'a Batch<T>,
+    taken: bool,
+}
+
+impl<'a, T> FlushGuard<'a, T> {
+    fn new(batch: &'a Batch<T>) -> Self {
+        Self {
+            batch,
+            taken: false,
+        }
+    }
+
+    /// Take the pending batch, so that the next request starts a new one
+    fn take(mut self) -> Pending<T> {
+        self.taken = true;
+        self.batch.0.lock().unwrap().take().unwrap_or_default()
+    }
+}
+
+impl<T> Drop for FlushGuard<'_, T> {
+    fn drop(&mut self) {
+        if !self.taken {
+            if let Ok(mut pending) = self.batch.0.lock() {
+                pending.take();
+            }
+        }
+    }
+}
+
+/// Collects the projects and versions requested within a short window,
+/// and gets each kind with a single bulk request
+///
+/// The first request in a window waits for the window to pass and then sends the bulk request,
+/// so no background task is needed. If the bulk request fails, all the requests in the batch return
+/// [`Error::BatchFailed`] with its error. If the bulk request doesn't include a requested item,
+/// that item is requested individually so that its error is returned.
+///
+/// The window is waited for using the [`Sleeper`](crate::Sleeper) of the [`Ferinth`] instance.
+///
+/// Example:
+/// ```rust
+/// # use ferinth::BatchFetcher;
+/// # use std::time::Duration;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), ferinth::Error> {
+/// let fetcher = BatchFetcher::new(ferinth::Ferinth::default(), Duration::from_millis(10));
+/// // These are fetched with one request
+/// let (sodium_mod, fabric_api) = tokio::join!(
+///     fetcher.get_project("AANobbMI"),
+///     fetcher.get_project("P7dR8mSH"),
+/// );
+/// assert_eq!(sodium_mod?.title, "Sodium");
+/// assert_eq!(fabric_api?.title, "Fabric API");
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchFetcher {
+    modrinth: Ferinth,
+    window: Duration,
+    projects: Arc<Batch<Project>>,
+    versions: Arc<Batch<Version>>,
+}
+
+impl BatchFetcher {
+    /// Create a batch fetcher that sends requests using `modrinth`,
+    /// batching together the requests made within `window` of each other.
+    pub fn new(modrinth: Ferinth, window: Duration) -> Self {
+        Self {
+            modrinth,
+            window,
+            projects: Default::default(),
+            versions: Default::default(),
+        }
+    }
+
+    /// Wait for the batching window to pass
+    async fn wait(&self) {
+        self.modrinth.sleeper.sleep(self.window).await;
+    }
+
+    /// Get a project with ID or slug `project_id`, in a batch with other projects
+    pub async fn get_project(&self, project_id: impl AsRef<str>) -> Result<Project> {
+        let project_id = project_id.as_ref();
+        check_id_slug(project_id)?;
+        let (receiver, leader) = self.projects.join(project_id);
+        if leader {
+            let guard = FlushGuard::new(&self.projects);
+            self.wait().await;
+            let pending = guard.take();
+            let mut ids: Vec<_> = pending.iter().map(|(id, _)| id.as_str()).collect();
+            ids.sort_unstable();
+            ids.dedup();
+            let projects = self.modrinth.get_multiple_projects(&ids).await.map_err(Arc::new);
+            for (id, sender) in pending {
+                let _ = sender.send(projects.as_ref().map_err(Arc::clone).map(|projects| {
+                    projects
+                        .iter()
+                        .find(|project| project.id == id || project.slug == id)
+                        .cloned()
+                }));
+            }
+        }
+        match receiver.await {
+            Ok(Ok(Some(project))) => Ok(project),
+            Ok(Err(error)) => Err(Error::BatchFailed(error)),
+            _ => self.modrinth.get_project(project_id).await,
+        }
+    }
+
+    /// Get a version with ID `version_id`, in a batch with other versions
+    pub async fn get_version(&self, version_id: impl AsRef<str>) -> Result<Version> {
+        let version_id = version_id.as_ref();
+        check_id_slug(version_id)?;
+        let (receiver, leader) = self.versions.join(version_id);
+        if leader {
+            let guard = FlushGuard::new(&self.versions);
+            self.wait().await;
+            let pending = guard.take();
+            let mut ids: Vec<_> = pending.iter().map(|(id, _)| id.as_str()).collect();
+            ids.sort_unstable();
+            ids.dedup();
+            let versions = self.modrinth.get_multiple_versions(&ids).await.map_err(Arc::new);
+            for (id, sender) in pending {
+                let _ = sender.send(versions.as_ref().map_err(Arc::clone).map(|versions| {
+                    versions.iter().find(|version| version.id == id).cloned()
+                }));
+            }
+        }
+        match receiver.await {
+            Ok(Ok(Some(version))) => Ok(version),
+            Ok(Err(error)) => Err(Error::BatchFailed(error)),
+            _ => self.modrinth.get_version(version_id).await,
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{request::API_URL_BASE, Ferinth, Result, RetryPolicy, Sleeper};
+use reqwest::{header, Client, Url};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+
+/// A builder to configure and construct a [`Ferinth`] instance
+///
+/// Example:
+/// ```rust
+/// # use std::time::Duration;
+/// # fn main() -> Result<(), ferinth::Error> {
+/// let modrinth = ferinth::Ferinth::builder()
+///     .user_agent(env!("CARGO_CRATE_NAME"), Some(env!("CARGO_PKG_VERSION")))
+///     .pool_max_idle_per_host(16)
+///     .pool_idle_timeout(Some(Duration::from_secs(30)))
+///     .build()?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Default)]
+pub struct FerinthBuilder {
+    program_name: Option<String>,
+    version: Option<String>,
+    contact: Option<String>,
+    authentication_token: Option<String>,
+    base_url: Option<Url>,
+    client: Option<Client>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pool_max_idle_per_host: Option<usize>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pool_idle_timeout: Option<Option<Duration>>,
+    retry_policy: Option<RetryPolicy>,
+    sleeper: Option<Arc<dyn Sleeper>>,
+    max_response_size: Option<u64>,
+    #[cfg(feature = "logging")]
+    rate_limit_warning_threshold: Option<usize>,
+    #[cfg(feature = "coalesce")]
+    coalesce_requests: bool,
+}
+
+impl std::fmt::Debug for FerinthBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("FerinthBuilder");
+        debug
+            .field("program_name", &self.program_name)
+            .field("version", &self.version)
+            .field("contact", &self.contact)
+            // Keep the token out of `Debug` output and logs
+            .field(
+                "authentication_token",
+                &self.authentication_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("base_url", &self.base_url)
+            .field("client", &self.client);
+        #[cfg(not(target_arch = "wasm32"))]
+        debug
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("pool_idle_timeout", &self.pool_idle_timeout);
+        debug
+            .field("retry_policy", &self.retry_policy)
+            .field("sleeper", &self.sleeper)
+            .field("max_response_size", &self.max_response_size);
+        #[cfg(feature = "logging")]
+        debug.field(
+            "rate_limit_warning_threshold",
+            &self.rate_limit_warning_threshold,
+        );
+        #[cfg(feature = "coalesce")]
+        debug.field("coalesce_requests", &self.coalesce_requests);
+        debug.finish()
+    }
+}
+
+impl FerinthBuilder {
+    /// Set the `program_name` and an optional `version` to use in the [user agent](https://docs.modrinth.com/api-spec/#section/User-Agents).
+    ///
+    /// If this is not called, the user agent will be set to this crate's name and version.
+    pub fn user_agent(mut self, program_name: &str, version: Option<&str>) -> Self {
+        self.program_name = Some(program_name.into());
+        self.version = version.map(Into::into);
+        self
+    }
+
+    /// Set the `contact` information to use in the user agent
+    pub fn contact(mut self, contact: &str) -> Self {
+        self.contact = Some(contact.into());
+        self
+    }
+
+    /// Set the GitHub `token` to use for authorisation
+    ///
+    /// The token is never included in the [`Debug`] output of the builder or the [`Ferinth`] instance.
+    ///
+    /// Example:
+    /// ```rust
+    /// # fn main() -> Result<(), ferinth::Error> {
+    /// let builder = ferinth::Ferinth::builder().authentication_token("gho_secret");
+    /// assert!(!format!("{:?}", builder).contains("gho_secret"));
+    /// let modrinth = builder.build()?;
+    /// assert!(!format!("{:?}", modrinth).contains("gho_secret"));
+    /// # Ok(()) }
+    /// ```
+    pub fn authentication_token(mut self, token: &str) -> Self {
+        self.authentication_token = Some(token.into());
+        self
+    }
+
+    /// Send API requests to `base_url` instead of `https://api.modrinth.com/v2/`,
+    /// such as to use Modrinth's staging server, a self-hosted Labrinth instance, or a mock server in tests.
+    ///
+    /// The base URL should include the API version, it is treated as a directory whether or not it ends with a slash.
+    ///
+    /// Example:
+    /// ```rust
+    /// # fn main() -> Result<(), ferinth::Error> {
+    /// let with_slash = ferinth::Ferinth::builder()
+    ///     .base_url("https://staging-api.modrinth.com/v2/".parse().unwrap())
+    ///     .build()?;
+    /// let without_slash = ferinth::Ferinth::builder()
+    ///     .base_url("https://staging-api.modrinth.com/v2".parse().unwrap())
+    ///     .build()?;
+    /// assert_eq!(with_slash.base_url(), without_slash.base_url());
+    /// assert_eq!(
+    ///     without_slash.base_url().join("project/sodium").unwrap().as_str(),
+    ///     "https://staging-api.modrinth.com/v2/project/sodium",
+    /// );
+    ///
+    /// // The base URL can also be the root of a host
+    /// let self_hosted = ferinth::Ferinth::builder()
+    ///     .base_url("http://localhost:8000".parse().unwrap())
+    ///     .build()?;
+    /// assert_eq!(self_hosted.base_url().as_str(), "http://localhost:8000/");
+    /// # Ok(()) }
+    /// ```
+    pub fn base_url(mut self, mut base_url: Url) -> Self {
+        // Without a trailing slash, the last segment would be replaced when joining paths
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+        self.base_url = Some(base_url);
+        self
+    }
+
+    /// Send requests using `client` instead of creating a new one,
+    /// such as to share its connection pool, proxy, and other configuration with the rest of your application.
+    ///
+    /// The user agent and authorisation header are still sent with each API request,
+    /// but the connection pool options are ignored as they are part of the client's configuration.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # fn main() -> Result<(), ferinth::Error> {
+    /// let client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(10))
+    ///     .build()?;
+    /// let modrinth = ferinth::Ferinth::builder()
+    ///     .client(client)
+    ///     .user_agent(env!("CARGO_CRATE_NAME"), Some(env!("CARGO_PKG_VERSION")))
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Set the maximum number of idle connections to keep alive per host
+    ///
+    /// This is not available on WASM, where the browser manages connections.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Set how long an idle connection is kept alive for, `None` disables the timeout
+    ///
+    /// This is not available on WASM, where the browser manages connections.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Retry rate limited requests according to `policy`.
+    ///
+    /// By default, rate limited requests are not retried and return [`Error::RateLimited`](crate::Error::RateLimited).
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Use `sleeper` to wait before retrying rate limited requests,
+    /// instead of Tokio's timer, or the browser's timer on WASM
+    pub fn sleeper(mut self, sleeper: impl Sleeper + 'static) -> Self {
+        self.sleeper = Some(Arc::new(sleeper));
+        self
+    }
+
+    /// Fail requests with [`Error::ResponseTooLarge`](crate::Error::ResponseTooLarge)
+    /// if the response body, including that of downloads, is larger than `bytes`.
+    ///
+    /// By default, there is no limit.
+    pub fn max_response_size(mut self, bytes: u64) -> Self {
+        self.max_response_size = Some(bytes);
+        self
+    }
+
+    /// Log a warning when fewer than `threshold` requests remain in the current rate limit window,
+    /// according to the `X-Ratelimit-Remaining` header of API responses
+    #[cfg(feature = "logging")]
+    pub fn rate_limit_warning_threshold(mut self, threshold: usize) -> Self {
+        self.rate_limit_warning_threshold = Some(threshold);
+        self
+    }
+
+    /// Share the response of a GET request with identical GET requests made while it is in flight,
+    /// instead of sending them all to the API.
+    ///
+    /// Requests are only coalesced within this instance and its clones.
+    /// If the request being shared fails or is cancelled, the requests waiting on it are sent individually.
+    #[cfg(feature = "coalesce")]
+    pub fn coalesce_requests(mut self, coalesce: bool) -> Self {
+        self.coalesce_requests = coalesce;
+        self
+    }
+
+    /// Build the [`Ferinth`] instance
+    ///
+    /// This function fails if the GitHub token provided is invalid,
+    /// or if the TLS backend could not be initialised.
+    pub fn build(self) -> Result<Ferinth> {
+        // A supplied client doesn't have our user agent, so it is sent with each request instead
+        let (client, user_agent) = match &self.client {
+            Some(client) => (client.clone(), Some(self.user_agent_string())),
+            None => (self.build_client()?, None),
+        };
+
+        Ok(Ferinth {
+            client,
+            user_agent,
+            base_url: self.base_url.unwrap_or_else(|| API_URL_BASE.clone()),
+            authorisation: self
+                .authentication_token
+                .map(|token| {
+                    let mut authorisation = header::HeaderValue::from_str(&token)?;
+                    // Keep the token out of `Debug` output and logs
+                    authorisation.set_sensitive(true);
+                    Ok::<_, header::InvalidHeaderValue>(authorisation)
+                })
+                .transpose()?,
+            retry_policy: self.retry_policy,
+            #[cfg(not(target_arch = "wasm32"))]
+            sleeper: self
+                .sleeper
+                .unwrap_or_else(|| Arc::new(crate::request::TokioSleeper)),
+            #[cfg(target_arch = "wasm32")]
+            sleeper: self
+                .sleeper
+                .unwrap_or_else(|| Arc::new(crate::request::GlooSleeper)),
+            max_response_size: self.max_response_size,
+            rate_limit: Default::default(),
+            #[cfg(feature = "logging")]
+            rate_limit_warning_threshold: self.rate_limit_warning_threshold,
+            #[cfg(feature = "coalesce")]
+            in_flight: self.coalesce_requests.then(Default::default),
+        })
+    }
+
+    /// Build a client with the user agent and connection pool options
+    fn build_client(&self) -> Result<Client> {
+        #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+        let mut builder = Client::builder().user_agent(self.user_agent_string());
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        Ok(builder.build()?)
+    }
+
+    /// The user agent made from the program name, version, and contact information
+    fn user_agent_string(&self) -> String {
+        let mut user_agent = match &self.program_name {
+            Some(program_name) => format!(
+                "{}{}",
+                program_name,
+                self.version
+                    .as_ref()
+                    .map_or("".into(), |version| format!("/{}", version))
+            ),
+            None => concat!(env!("CARGO_CRATE_NAME"), "/", env!("CARGO_PKG_VERSION")).into(),
+        };
+        if let Some(contact) = &self.contact {
+            user_agent.push_str(&format!(" ({})", contact));
+        }
+        user_agent
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Ferinth, Result};
+use bytes::Bytes;
+use futures_channel::oneshot;
+use reqwest::Url;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+/// The GET requests that are currently in flight,
+/// along with the requests waiting for their responses
+#[derive(Debug, Default)]
+pub(crate) struct InFlight(Mutex<HashMap<Url, Vec<oneshot::Sender<Bytes>>>>);
+
+enum Role {
+    /// This request is the first one to `url`, so it performs the request
+    Leader(LeaderGuard),
+    /// An identical request is already in flight, so its response can be shared
+    Follower(oneshot::Receiver<Bytes>),
+}
+
+/// Removes the in flight request when dropped, so that a cancelled or failed request
+/// doesn't leave its followers waiting (they will make their own requests instead)
+struct LeaderGuard {
+    in_flight: Arc<InFlight>,
+    url: Url,
+    finished: bool,
+}
+
+impl InFlight {
+    fn join(self: &Arc<Self>, url: &Url) -> Role {
+        let mut in_flight = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        match in_flight.get_mut(url) {
+            Some(followers) => {
+                let (sender, receiver) = oneshot::channel();
+                followers.push(sender);
+                Role::Follower(receiver)
+            }
+            None => {
+                in_flight.insert(url.clone(), Vec::new());
+                Role::Leader(LeaderGuard {
+                    in_flight: Arc::clone(self),
+                    url: url.clone(),
+                    finished: false,
+                })
+            }
+        }
+    }
+}
+
+impl LeaderGuard {
+    /// Send the response `body` to all the followers
+    fn finish(mut self, body: &Bytes) {
+        // The entry is removed here, so the drop must not remove a newer leader's entry
+        self.finished = true;
+        let followers = self
+            .in_flight
+            .0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.url);
+        for follower in followers.into_iter().flatten() {
+            let _ = follower.send(body.clone());
+        }
+    }
+}
+
+impl Drop for LeaderGuard {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.in_flight
+                .0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .remove(&self.url);
+        }
+    }
+}
+
+impl Ferinth {
+    /// Perform a GET request to `url`, sharing the response with identical requests that are already in flight
+    pub(crate) async fn get_coalesced(&self, in_flight: &Arc<InFlight>, url: Url) -> Result<Bytes> {
+        match in_flight.join(&url) {
+            Role::Follower(receiver) => match receiver.await {
+                Ok(body) => Ok(body),
+                // The leader failed or was cancelled
+                Err(oneshot::Canceled) => self.get_bytes(url).await,
+            },
+            Role::Leader(guard) => {
+                let body = self.get_bytes(url).await?;
+                guard.finish(&body);
+                Ok(body)
+            }
+        }
+    }
+}
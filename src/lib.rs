@@ -10,33 +10,181 @@
 //! - All structure definitions based on <https://docs.modrinth.com/api-spec/>
 //! - All of the GET and POST calls that don't require authentication
 //!
-//! This crate uses [Rustls](https://docs.rs/rustls/) rather than OpenSSL, because OpenSSL is outdated and slower.
+//! By default, this crate uses [Rustls](https://docs.rs/rustls/) rather than OpenSSL, because OpenSSL is outdated and slower.
+//! If you need the platform's native TLS (e.g. to use the system certificate store),
+//! disable the default `rustls-tls` feature and enable the `native-tls` feature instead:
+//!
+//! ```toml
+//! [dependencies]
+//! ferinth = { version = "2", default-features = false, features = ["native-tls"] }
+//! ```
+//!
+//! Exactly one of these features must be enabled.
+//!
+//! Enable the `markdown` feature to render project bodies to sanitised HTML
+//! with `Project::body_html`.
 //!
 //! The following features still need to be implemented
-//! - Search projects
 //! - Some types of requests
+//!
+//! ## Cancellation
+//!
+//! Dropping the future of an API call before it completes aborts its request,
+//! for example when a search is superseded by a newer one as the user types.
+//! The futures are cancel safe, no lock is held across their `.await` points,
+//! so a dropped call never leaves the [`Ferinth`] instance in a bad state.
+//!
+//! The features that share requests between calls hold guards across `.await` points,
+//! which clean up after the call when it is dropped:
+//! - With request coalescing (the `coalesce` feature), if the call sending a shared request
+//!   is dropped, the calls waiting for its response send their own requests instead.
+//! - With a `BatchFetcher` (the `batch` feature), if the call that would send a batch is dropped
+//!   while waiting for the batching window, the batch is discarded
+//!   and the other calls in it send their own requests instead.
+//!
+//! ```rust
+//! # use ferinth::structures::search::SearchIndex;
+//! # use std::time::Duration;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), ferinth::Error> {
+//! let modrinth = ferinth::Ferinth::default();
+//! let (keystroke, mut keystrokes) = tokio::sync::mpsc::channel(1);
+//! // The user types another letter while the first search is in flight
+//! tokio::spawn(async move {
+//!     tokio::time::sleep(Duration::from_millis(1)).await;
+//!     keystroke.send("sodium e").await
+//! });
+//! tokio::select! {
+//!     results = modrinth.search("sodium", SearchIndex::Relevance, 0, 10, &[]) => {
+//!         println!("{} results", results?.total_hits);
+//!     }
+//!     Some(query) = keystrokes.recv() => {
+//!         // The first search's future has been dropped, which aborted its request
+//!         let results = modrinth.search(query, SearchIndex::Relevance, 0, 10, &[]).await?;
+//!         println!("{} results", results.total_hits);
+//!     }
+//! }
+//! # Ok(()) }
+//! ```
 
 mod api_calls;
+#[cfg(feature = "batch")]
+mod batch;
+mod builder;
+#[cfg(feature = "coalesce")]
+mod coalesce;
 mod request;
 pub mod structures;
 mod url_join_ext;
 
+#[cfg(feature = "batch")]
+pub use batch::BatchFetcher;
+pub use builder::FerinthBuilder;
+pub use request::{RateLimit, RetryPolicy, SleepFuture, Sleeper};
+/// The `semver` crate, whose versions are returned by [`Version::semver`](structures::version::Version::semver)
+pub use semver;
 use reqwest::{header, Client};
 
+#[cfg(all(feature = "rustls-tls", feature = "native-tls"))]
+compile_error!("The `rustls-tls` and `native-tls` features are mutually exclusive, enable only one of them");
+#[cfg(not(any(feature = "rustls-tls", feature = "native-tls")))]
+compile_error!("A TLS backend must be selected, enable either the `rustls-tls` or the `native-tls` feature");
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
-    #[error("A given string was not base62 compliant")]
-    NotBase62,
+    /// The given string is neither a base62 ID nor a slug, the string is included.
+    /// This is checked before sending the request.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// assert!(matches!(
+    ///     modrinth.get_project("not a slug").await,
+    ///     Err(ferinth::Error::InvalidIDorSlug(value)) if value == "not a slug",
+    /// ));
+    /// assert!(matches!(
+    ///     modrinth.get_project("sodiüm").await,
+    ///     Err(ferinth::Error::InvalidIDorSlug(_)),
+    /// ));
+    ///
+    /// // Slugs and IDs are accepted, so these fail to connect to the unreachable API instead
+    /// let unreachable = ferinth::Ferinth::builder()
+    ///     .base_url("http://127.0.0.1:9/v2/".parse().unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// for valid in ["fabric-api", "AANobbMI", "ok-zoomer", "mod_menu", "(hello)!"] {
+    ///     assert!(matches!(
+    ///         unreachable.get_project(valid).await,
+    ///         Err(ferinth::Error::ReqwestError(_)),
+    ///     ));
+    /// }
+    /// # }
+    /// ```
+    #[error("`{}` is not a valid ID or slug", .0)]
+    InvalidIDorSlug(String),
     #[error("A given string was not SHA1 compliant")]
     NotSHA1,
-    #[error("You have been rate limited, please wait for {} seconds", .0)]
-    RateLimitExceeded(usize),
+    #[error("A given string was not SHA512 compliant")]
+    NotSHA512,
+    /// The request was rate limited, `retry_after` is the number of seconds to wait before retrying if the server said
+    #[error("You have been rate limited{}", retry_after.map_or(String::new(), |seconds| format!(", please wait for {} seconds", seconds)))]
+    RateLimited { retry_after: Option<u64> },
+    /// Access was forbidden, with the server's description of why if available,
+    /// such as the project being withheld by the moderators
+    #[error("You do not have permission to access this resource: {}", .0.as_deref().unwrap_or("no reason was given"))]
+    Forbidden(Option<String>),
+    /// The requested resource doesn't exist, with the response's body
+    #[error("The requested resource was not found: {}", .0)]
+    NotFound(String),
+    /// The authorisation token is missing or invalid, with the response's body
+    #[error("You are not authorised to access this resource: {}", .0)]
+    Unauthorized(String),
+    /// The request failed with a status that doesn't have its own variant, with the response's body
+    #[error("The request failed with status {}: {}", .0, .1)]
+    Status(reqwest::StatusCode, String),
     #[error("{}", .0)]
     ReqwestError(#[from] reqwest::Error),
     #[error("{}", .0)]
     JSONError(#[from] serde_json::Error),
     #[error("The GitHub token provided is invalid")]
     InvalidGitHubToken(#[from] header::InvalidHeaderValue),
+    #[error("Expected a JSON response, but received {content_type} with status {status}: {snippet}")]
+    UnexpectedResponse {
+        status: reqwest::StatusCode,
+        content_type: String,
+        /// The start of the response body
+        snippet: String,
+    },
+    #[error("{}", .0)]
+    IOError(#[from] std::io::Error),
+    #[error("The downloaded file does not match its hashes")]
+    HashMismatch,
+    #[error("No download links were provided")]
+    NoDownloads,
+    #[error("No files were provided to upload")]
+    NoFiles,
+    /// The response body was larger than the maximum response size, in bytes
+    #[error("The response body is larger than the limit of {} bytes", .0)]
+    ResponseTooLarge(u64),
+    /// The bulk request that a [`BatchFetcher`] request was batched into failed with this error
+    #[cfg(feature = "batch")]
+    #[error("The batched request failed: {}", .0)]
+    BatchFailed(std::sync::Arc<Error>),
+    /// The dependencies being resolved form a cycle, `path` contains the IDs of the projects in it,
+    /// starting and ending with the same project
+    #[error("The dependencies form a cycle: {}", path.join(" -> "))]
+    DependencyCycle { path: Vec<String> },
+    /// The given game version isn't a release version number, or no release versions match it
+    #[error("`{}` is not a valid game version", .0)]
+    InvalidGameVersion(String),
+    /// The project's authors can't change the project's status from `from` to `to`
+    #[error("The project's status can't be changed from {from:?} to {to:?}")]
+    InvalidStatusTransition {
+        from: structures::project::ProjectStatus,
+        to: structures::project::ProjectStatus,
+    },
 }
 
 pub(crate) type Result<T> = std::result::Result<T, Error>;
@@ -56,24 +204,62 @@ pub(crate) type Result<T> = std::result::Result<T, Error>;
 #[derive(Debug, Clone)]
 pub struct Ferinth {
     client: Client,
+    /// The user agent to send with API requests, if the client doesn't already send it
+    user_agent: Option<String>,
+    /// The base URL of the API, which the endpoints' paths are joined to
+    base_url: reqwest::Url,
+    /// The authorisation header to send with API requests.
+    /// This is not set as a default header so that it isn't leaked to other hosts when downloading files.
+    authorisation: Option<header::HeaderValue>,
+    retry_policy: Option<RetryPolicy>,
+    sleeper: std::sync::Arc<dyn Sleeper>,
+    max_response_size: Option<u64>,
+    /// The rate limit from the latest API response, shared between clones
+    rate_limit: std::sync::Arc<std::sync::Mutex<Option<RateLimit>>>,
+    /// Warn when fewer than this many requests remain in the rate limit window
+    #[cfg(feature = "logging")]
+    rate_limit_warning_threshold: Option<usize>,
+    /// The GET requests in flight, if request coalescing is enabled
+    #[cfg(feature = "coalesce")]
+    in_flight: Option<std::sync::Arc<coalesce::InFlight>>,
 }
 
 impl Default for Ferinth {
     fn default() -> Self {
-        Self {
-            client: Client::builder()
-                .user_agent(concat!(
-                    env!("CARGO_CRATE_NAME"),
-                    "/",
-                    env!("CARGO_PKG_VERSION")
-                ))
-                .build()
-                .expect("TLS backend failed to initialise"),
-        }
+        Self::builder()
+            .build()
+            .expect("TLS backend failed to initialise")
     }
 }
 
 impl Ferinth {
+    /// Create a [`FerinthBuilder`] to configure a new instance
+    pub fn builder() -> FerinthBuilder {
+        FerinthBuilder::default()
+    }
+
+    /// The base URL that API requests are sent to, which always ends with a slash
+    ///
+    /// Example:
+    /// ```rust
+    /// let modrinth = ferinth::Ferinth::default();
+    /// assert_eq!(modrinth.base_url().as_str(), "https://api.modrinth.com/v2/");
+    /// ```
+    pub fn base_url(&self) -> &reqwest::Url {
+        &self.base_url
+    }
+
+    /// The state of the API's rate limit according to the latest response that included it,
+    /// or `None` if no such response has been received yet
+    ///
+    /// This is shared between clones of this instance.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        *self
+            .rate_limit
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
     /// Instantiate the container with the provided [user agent](https://docs.modrinth.com/api-spec/#section/User-Agents) information,
     /// and an optional GitHub token for authorisation.
     ///
@@ -86,24 +272,13 @@ impl Ferinth {
         contact: Option<&str>,
         authorisation: Option<&str>,
     ) -> Result<Self> {
-        Ok(Self {
-            client: Client::builder()
-                .user_agent(format!(
-                    "{}{}{}",
-                    program_name,
-                    version.map_or("".into(), |version| format!("/{}", version)),
-                    contact.map_or("".into(), |contact| format!(" ({})", contact))
-                ))
-                .default_headers(if let Some(authorisation) = authorisation {
-                    header::HeaderMap::from_iter(vec![(
-                        header::AUTHORIZATION,
-                        header::HeaderValue::from_str(authorisation)?,
-                    )])
-                } else {
-                    header::HeaderMap::new()
-                })
-                .build()
-                .unwrap(),
-        })
+        let mut builder = Self::builder().user_agent(program_name, version);
+        if let Some(contact) = contact {
+            builder = builder.contact(contact);
+        }
+        if let Some(authorisation) = authorisation {
+            builder = builder.authentication_token(authorisation);
+        }
+        builder.build()
     }
 }
@@ -1,29 +1,520 @@
 use crate::{Error, Ferinth, Result};
-use reqwest::{StatusCode, Url};
+use bytes::{Bytes, BytesMut};
+use reqwest::{
+    header::{HeaderMap, AUTHORIZATION, CONTENT_TYPE, USER_AGENT},
+    Method, RequestBuilder, Response, StatusCode, Url,
+};
 use serde::{de::DeserializeOwned, Serialize};
+use std::{fmt::Debug, future::Future, pin::Pin, time::Duration};
 
 lazy_static::lazy_static! {
     pub(crate) static ref API_URL_BASE: Url = Url::parse("https://api.modrinth.com/v2/").unwrap();
 }
 
+/// How rate limited requests should be retried
+///
+/// When a request is rate limited, the delay before retrying it is
+/// taken from the `Retry-After` or `X-Ratelimit-Reset` headers if the server sent them,
+/// otherwise it starts from `base_delay` and doubles after every retry, up to `max_delay`.
+/// If the server asks to wait for longer than `max_delay`, the request is not retried.
+/// Randomised jitter is added on top of the delay if enabled,
+/// so that many clients that were rate limited together don't all retry at the same time.
+///
+/// Example:
+/// ```rust
+/// # use ferinth::RetryPolicy;
+/// # use std::time::Duration;
+/// # fn main() -> Result<(), ferinth::Error> {
+/// let modrinth = ferinth::Ferinth::builder()
+///     .retry_policy(RetryPolicy {
+///         max_retries: 5,
+///         max_total_delay: Duration::from_secs(300),
+///         ..RetryPolicy::default()
+///     })
+///     .build()?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of times a request is retried
+    pub max_retries: usize,
+    /// The delay before the first retry when the server doesn't specify one
+    pub base_delay: Duration,
+    /// The maximum delay before a single retry.
+    /// The backoff is capped to this, but if the server asks to wait longer, the rate limit error is returned instead of retrying early.
+    pub max_delay: Duration,
+    /// The maximum total time spent waiting to retry a request,
+    /// the rate limit error is returned if retrying would exceed this
+    pub max_total_delay: Duration,
+    /// Whether to add randomised jitter to the delays
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            max_total_delay: Duration::from_secs(120),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Calculate the delay before the `retry`th retry, using the `headers` of the rate limited response.
+    /// Returns `None` if the server asks to wait for longer than `max_delay`.
+    fn delay(&self, retry: u32, headers: &HeaderMap) -> Option<Duration> {
+        let delay = match retry_after(headers) {
+            // Retrying before the server allows it would only be rate limited again
+            Some(delay) if delay > self.max_delay => return None,
+            Some(delay) => delay,
+            None => self
+                .base_delay
+                .saturating_mul(2_u32.saturating_pow(retry))
+                .min(self.max_delay),
+        };
+        Some(if self.jitter {
+            delay + delay.mul_f64(random_fraction() / 2.)
+        } else {
+            delay
+        })
+    }
+}
+
+/// The future returned by [`Sleeper::sleep`]
+#[cfg(not(target_arch = "wasm32"))]
+pub type SleepFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+/// The future returned by [`Sleeper::sleep`]
+#[cfg(target_arch = "wasm32")]
+pub type SleepFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// A timer used to wait before retrying rate limited requests
+///
+/// Tokio's timer is used by default, or the browser's timer through `gloo-timers` on WASM.
+/// To use another runtime's timer, implement this and provide it to
+/// [`FerinthBuilder::sleeper`](crate::FerinthBuilder::sleeper).
+///
+/// Example:
+/// ```rust
+/// # use ferinth::{Sleeper, SleepFuture};
+/// # use std::time::Duration;
+/// #[derive(Debug)]
+/// struct TokioSleeper;
+///
+/// impl Sleeper for TokioSleeper {
+///     fn sleep(&self, duration: Duration) -> SleepFuture {
+///         Box::pin(tokio::time::sleep(duration))
+///     }
+/// }
+/// ```
+pub trait Sleeper: Debug + Send + Sync {
+    /// Wait for `duration`
+    fn sleep(&self, duration: Duration) -> SleepFuture;
+}
+
+/// A [`Sleeper`] that uses Tokio's timer
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct TokioSleeper;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Sleeper for TokioSleeper {
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// A [`Sleeper`] that uses the browser's timer
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct GlooSleeper;
+
+#[cfg(target_arch = "wasm32")]
+impl Sleeper for GlooSleeper {
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        Box::pin(gloo_timers::future::sleep(duration))
+    }
+}
+
+/// Get how long the server wants us to wait from the `headers`
+///
+/// Only the durations of the delays are used for retries, rather than `std::time::Instant`, which is not available on WASM.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let header = |name| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+    };
+    if let Some(retry_after) = header("Retry-After") {
+        if let Ok(seconds) = retry_after.parse() {
+            return Some(Duration::from_secs(seconds));
+        }
+        if let Ok(date) = chrono::DateTime::parse_from_rfc2822(retry_after) {
+            return Some(
+                (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+                    .to_std()
+                    .unwrap_or_default(),
+            );
+        }
+    }
+    header("X-Ratelimit-Reset")
+        .and_then(|reset| reset.parse().ok())
+        .map(Duration::from_secs)
+}
+
+/// The state of the API's rate limit, as of the latest response
+///
+/// Example:
+/// ```rust
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), ferinth::Error> {
+/// # let modrinth = ferinth::Ferinth::default();
+/// modrinth.get_project("sodium").await?;
+/// if let Some(rate_limit) = modrinth.rate_limit() {
+///     if rate_limit.remaining < 10 {
+///         tokio::time::sleep(rate_limit.reset).await;
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The maximum number of requests that can be made in a rate limit window
+    pub limit: usize,
+    /// The number of requests that can still be made in the current window
+    pub remaining: usize,
+    /// The time until the current window ends and the remaining requests are reset
+    pub reset: Duration,
+}
+
+impl RateLimit {
+    /// Parse the rate limit from the `X-Ratelimit-*` headers in `headers`,
+    /// or return `None` if any of them are missing or malformed
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+        };
+        Some(Self {
+            limit: header("X-Ratelimit-Limit")?,
+            remaining: header("X-Ratelimit-Remaining")?,
+            reset: Duration::from_secs(header("X-Ratelimit-Reset")? as u64),
+        })
+    }
+}
+
+/// Generate a random number between 0 and 1
+fn random_fraction() -> f64 {
+    use std::{
+        collections::hash_map::RandomState,
+        hash::{BuildHasher, Hasher},
+    };
+    // `RandomState` is randomly seeded every time it is created
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1_u64 << 53) as f64
+}
+
+/// A part of a `multipart/form-data` request body
+pub(crate) struct FormPart<'a> {
+    pub name: &'a str,
+    pub filename: Option<&'a str>,
+    pub content_type: &'a str,
+    pub content: &'a [u8],
+}
+
+/// Generate a random boundary for a `multipart/form-data` body
+fn multipart_boundary() -> String {
+    use std::{
+        collections::hash_map::RandomState,
+        hash::{BuildHasher, Hasher},
+    };
+    format!(
+        "ferinth-{:016x}{:016x}",
+        RandomState::new().build_hasher().finish(),
+        RandomState::new().build_hasher().finish(),
+    )
+}
+
+/// Encode `parts` as a `multipart/form-data` body separated by `boundary`
+///
+/// The body is encoded up front rather than using reqwest's streamed multipart forms,
+/// so that the request can be cloned to retry it if it is rate limited.
+fn encode_multipart(parts: &[FormPart], boundary: &str) -> Bytes {
+    // Quotes and line breaks would end the header values early
+    let escape = |value: &str| {
+        value
+            .replace('"', "%22")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    };
+
+    let mut body = BytesMut::new();
+    for part in parts {
+        body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+        body.extend_from_slice(
+            format!("Content-Disposition: form-data; name=\"{}\"", escape(part.name)).as_bytes(),
+        );
+        if let Some(filename) = part.filename {
+            body.extend_from_slice(format!("; filename=\"{}\"", escape(filename)).as_bytes());
+        }
+        body.extend_from_slice(
+            format!("\r\nContent-Type: {}\r\n\r\n", part.content_type).as_bytes(),
+        );
+        body.extend_from_slice(part.content);
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+    body.freeze()
+}
+
+/// The maximum number of characters of an unexpected response's body to keep
+const SNIPPET_LENGTH: usize = 256;
+
+/// Check whether `response` is not JSON, responses without a content type are assumed to be JSON
+fn is_not_json(response: &Response) -> bool {
+    response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .is_some_and(|content_type| !content_type.contains("json"))
+}
+
+/// Create an [`Error::UnexpectedResponse`] from a `response` that isn't JSON, such as a gateway's HTML error page
+async fn unexpected_response(response: Response) -> Error {
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .map(|content_type| String::from_utf8_lossy(content_type.as_bytes()).into())
+        .unwrap_or_default();
+    // Only read as much of the body as is needed for the snippet, since an error page could be huge
+    #[cfg(not(target_arch = "wasm32"))]
+    let body = {
+        let mut body = Vec::new();
+        let mut response = response;
+        while body.len() < SNIPPET_LENGTH * 4 {
+            match response.chunk().await {
+                Ok(Some(chunk)) => body.extend_from_slice(&chunk),
+                _ => break,
+            }
+        }
+        body
+    };
+    // The body can't be read in chunks on WASM, so all of it is read
+    #[cfg(target_arch = "wasm32")]
+    let body = response.bytes().await.unwrap_or_default();
+    let snippet = String::from_utf8_lossy(&body)
+        .trim()
+        .chars()
+        .take(SNIPPET_LENGTH)
+        .collect();
+    Error::UnexpectedResponse {
+        status,
+        content_type,
+        snippet,
+    }
+}
+
+/// Deserialise a JSON response `body`
+///
+/// Empty bodies are deserialised from `null`, so that endpoints without a response body can return `()`.
+fn deserialise_body<T: DeserializeOwned>(body: &[u8]) -> Result<T> {
+    if body.is_empty() {
+        return Ok(T::deserialize(serde_json::Value::Null)?);
+    }
+    Ok(serde_json::from_slice(body)?)
+}
+
+/// The body of an error response from the API
+#[derive(serde::Deserialize)]
+struct ApiError {
+    description: String,
+}
+
 impl Ferinth {
+    /// Read the body of `response`, failing if it is larger than the maximum response size
+    pub(crate) async fn read_body(&self, response: Response) -> Result<Bytes> {
+        let limit = match self.max_response_size {
+            Some(limit) => limit,
+            None => return Ok(response.bytes().await?),
+        };
+        if response.content_length().is_some_and(|length| length > limit) {
+            return Err(Error::ResponseTooLarge(limit));
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let body = {
+            let mut body = BytesMut::new();
+            let mut response = response;
+            while let Some(chunk) = response.chunk().await? {
+                if (body.len() + chunk.len()) as u64 > limit {
+                    return Err(Error::ResponseTooLarge(limit));
+                }
+                body.extend_from_slice(&chunk);
+            }
+            body.freeze()
+        };
+        // The body can't be read in chunks on WASM, so it is only checked once all of it is read
+        #[cfg(target_arch = "wasm32")]
+        let body = response.bytes().await?;
+        if body.len() as u64 > limit {
+            return Err(Error::ResponseTooLarge(limit));
+        }
+        Ok(body)
+    }
+
+    /// Read the body of an error `response` as text, for debugging.
+    /// The body is left empty if it can't be read.
+    async fn body_text(&self, response: Response) -> String {
+        self.read_body(response)
+            .await
+            .map(|body| String::from_utf8_lossy(&body).into())
+            .unwrap_or_default()
+    }
+
+    /// Read the JSON body of `response`, which is empty if the response has no content
+    async fn json_body(&self, response: Response) -> Result<Bytes> {
+        if StatusCode::NO_CONTENT == response.status() {
+            return Ok(Bytes::new());
+        }
+        if is_not_json(&response) {
+            return Err(unexpected_response(response).await);
+        }
+        self.read_body(response).await
+    }
+
+    /// Deserialise the JSON body of `response`
+    async fn json<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
+        deserialise_body(&self.json_body(response).await?)
+    }
+
+    /// Create a request to the API with `method` and `url`, with the user agent and authorisation headers if available
+    fn request(&self, method: Method, url: Url) -> RequestBuilder {
+        let request = self.unauthorised_request(method, url);
+        match &self.authorisation {
+            Some(authorisation) => request.header(AUTHORIZATION, authorisation),
+            None => request,
+        }
+    }
+
+    /// Create a request with `method` and `url`, with the user agent if it isn't the client's own.
+    /// The authorisation header is left out so that the token isn't sent to hosts other than the API.
+    pub(crate) fn unauthorised_request(&self, method: Method, url: Url) -> RequestBuilder {
+        let request = self.client.request(method, url);
+        match &self.user_agent {
+            Some(user_agent) => request.header(USER_AGENT, user_agent),
+            None => request,
+        }
+    }
+
+    /// Send `request`, retrying it according to the retry policy if it is rate limited
+    pub(crate) async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let mut retries = 0;
+        let mut total_delay = Duration::ZERO;
+        loop {
+            let response = request
+                .try_clone()
+                .expect("Request bodies should not be streams")
+                .send()
+                .await?;
+            self.record_rate_limit(response.headers());
+            #[cfg(feature = "logging")]
+            self.warn_rate_limit(response.headers());
+            let status = response.status();
+            if StatusCode::TOO_MANY_REQUESTS != status {
+                if status.is_success() {
+                    return Ok(response);
+                }
+                return Err(match status {
+                    StatusCode::FORBIDDEN => Error::Forbidden(
+                        self.read_body(response)
+                            .await
+                            .ok()
+                            .and_then(|body| serde_json::from_slice::<ApiError>(&body).ok())
+                            .map(|error| error.description),
+                    ),
+                    StatusCode::NOT_FOUND => Error::NotFound(self.body_text(response).await),
+                    StatusCode::UNAUTHORIZED => {
+                        Error::Unauthorized(self.body_text(response).await)
+                    }
+                    _ if is_not_json(&response) => unexpected_response(response).await,
+                    _ => Error::Status(status, self.body_text(response).await),
+                });
+            }
+
+            if let Some(policy) = &self.retry_policy {
+                let delay = policy.delay(retries, response.headers());
+                if let Some(delay) = delay.filter(|delay| {
+                    (retries as usize) < policy.max_retries
+                        && total_delay + *delay <= policy.max_total_delay
+                }) {
+                    self.sleeper.sleep(delay).await;
+                    retries += 1;
+                    total_delay += delay;
+                    continue;
+                }
+            }
+            return Err(Error::RateLimited {
+                retry_after: retry_after(response.headers()).map(|delay| delay.as_secs()),
+            });
+        }
+    }
+
+    /// Store the rate limit in `headers`, if the response has one
+    fn record_rate_limit(&self, headers: &HeaderMap) {
+        if let Some(rate_limit) = RateLimit::from_headers(headers) {
+            *self
+                .rate_limit
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(rate_limit);
+        }
+    }
+
+    /// Log a warning if the remaining rate limit in `headers` is below the warning threshold
+    #[cfg(feature = "logging")]
+    fn warn_rate_limit(&self, headers: &HeaderMap) {
+        let remaining = headers
+            .get("X-Ratelimit-Remaining")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<usize>().ok());
+        if let (Some(threshold), Some(remaining)) = (self.rate_limit_warning_threshold, remaining) {
+            if remaining < threshold {
+                log::warn!(
+                    "Only {} requests remain in the Modrinth API's rate limit window",
+                    remaining
+                );
+            }
+        }
+    }
+
     /// Perform a GET request to `url`, and deserialise the response
     pub(crate) async fn get<T>(&self, url: Url) -> Result<T>
     where
         T: DeserializeOwned,
     {
-        let response = self.client.get(url).send().await?;
-        if StatusCode::TOO_MANY_REQUESTS == response.status() {
-            Err(Error::RateLimitExceeded(
-                response
-                    .headers()
-                    .get("X-Ratelimit-Reset")
-                    .map(|header| header.to_str().unwrap().parse().unwrap())
-                    .unwrap(),
-            ))
-        } else {
-            Ok(response.error_for_status()?.json().await?)
+        #[cfg(feature = "coalesce")]
+        if let Some(in_flight) = &self.in_flight {
+            return deserialise_body(&self.get_coalesced(in_flight, url).await?);
         }
+        self.json(self.send(self.request(Method::GET, url)).await?)
+            .await
+    }
+
+    /// Perform a GET request to `url`, and return the JSON response's body without deserialising it
+    #[cfg(feature = "coalesce")]
+    pub(crate) async fn get_bytes(&self, url: Url) -> Result<Bytes> {
+        self.json_body(self.send(self.request(Method::GET, url)).await?)
+            .await
+    }
+
+    /// Perform a GET request to `url` for a file download, without the authorisation header,
+    /// and return the response for its body to be read
+    pub(crate) async fn get_download(&self, url: Url) -> Result<Response> {
+        self.send(self.unauthorised_request(Method::GET, url)).await
     }
 
     /// Perform a GET request to `url` with `query` parameters, and deserialise the response
@@ -47,18 +538,34 @@ impl Ferinth {
         T: DeserializeOwned,
         B: Serialize + ?Sized,
     {
-        let response = self.client.post(url).json(body).send().await?;
-        if StatusCode::TOO_MANY_REQUESTS == response.status() {
-            Err(Error::RateLimitExceeded(
-                response
-                    .headers()
-                    .get("X-Ratelimit-Reset")
-                    .map(|header| header.to_str().unwrap().parse().unwrap())
-                    .unwrap(),
-            ))
-        } else {
-            Ok(response.error_for_status()?.json().await?)
-        }
+        self.json(self.send(self.request(Method::POST, url).json(body)).await?)
+            .await
+    }
+
+    /// Perform a POST request to `url` without a body, ignoring the response's body
+    pub(crate) async fn post_no_content(&self, url: Url) -> Result<()> {
+        self.send(self.request(Method::POST, url)).await?;
+        Ok(())
+    }
+
+    /// Perform a POST request to `url` with a `multipart/form-data` body of `parts`, and deserialise the response
+    pub(crate) async fn post_multipart<T>(&self, url: Url, parts: &[FormPart<'_>]) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let boundary = multipart_boundary();
+        self.json(
+            self.send(
+                self.request(Method::POST, url)
+                    .header(
+                        CONTENT_TYPE,
+                        format!("multipart/form-data; boundary={}", boundary),
+                    )
+                    .body(encode_multipart(parts, &boundary)),
+            )
+            .await?,
+        )
+        .await
     }
 
     /// Perform a POST request to `url` with `body` and `query` parameters, and deserialise the response
@@ -77,4 +584,158 @@ impl Ferinth {
         url.query_pairs_mut().extend_pairs(query);
         self.post(url, body).await
     }
+
+    /// Perform a POST request to `url` with the raw `body` of `content_type` and `query` parameters, ignoring the response's body
+    pub(crate) async fn post_raw_with_query<K, V>(
+        &self,
+        mut url: Url,
+        body: Bytes,
+        content_type: &str,
+        query: &[(K, V)],
+    ) -> Result<()>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        url.query_pairs_mut().extend_pairs(query);
+        self.send(
+            self.request(Method::POST, url)
+                .header(CONTENT_TYPE, content_type)
+                .body(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Perform a PATCH request to `url` with the raw `body` of `content_type` and `query` parameters, ignoring the response's body
+    pub(crate) async fn patch_raw_with_query<K, V>(
+        &self,
+        mut url: Url,
+        body: Bytes,
+        content_type: &str,
+        query: &[(K, V)],
+    ) -> Result<()>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        url.query_pairs_mut().extend_pairs(query);
+        self.send(
+            self.request(Method::PATCH, url)
+                .header(CONTENT_TYPE, content_type)
+                .body(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Perform a HEAD request to `url` with `query` parameters, and return whether the resource exists
+    pub(crate) async fn exists_with_query<K, V>(
+        &self,
+        mut url: Url,
+        query: &[(K, V)],
+    ) -> Result<bool>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        url.query_pairs_mut().extend_pairs(query);
+        match self.send(self.request(Method::HEAD, url)).await {
+            Ok(_) => Ok(true),
+            Err(Error::NotFound(_)) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Perform a PATCH request to `url` without a body, ignoring the response's body
+    pub(crate) async fn patch_no_content(&self, url: Url) -> Result<()> {
+        self.send(self.request(Method::PATCH, url)).await?;
+        Ok(())
+    }
+
+    /// Perform a DELETE request to `url`, ignoring the response's body
+    pub(crate) async fn delete(&self, url: Url) -> Result<()> {
+        self.send(self.request(Method::DELETE, url)).await?;
+        Ok(())
+    }
+
+    /// Perform a DELETE request to `url` with `query` parameters, ignoring the response's body
+    pub(crate) async fn delete_with_query<K, V>(&self, mut url: Url, query: &[(K, V)]) -> Result<()>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        url.query_pairs_mut().extend_pairs(query);
+        self.delete(url).await
+    }
+
+    /// Perform a PATCH request to `url` with `body`, ignoring the response's body
+    pub(crate) async fn patch<B>(&self, url: Url, body: &B) -> Result<()>
+    where
+        B: Serialize + ?Sized,
+    {
+        self.send(self.request(Method::PATCH, url).json(body)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_multipart_json_and_file() {
+        let parts = [
+            FormPart {
+                name: "data",
+                filename: None,
+                content_type: "application/json",
+                content: br#"{"file_parts":["mod.jar"]}"#,
+            },
+            FormPart {
+                name: "mod.jar",
+                filename: Some("mod.jar"),
+                content_type: "application/octet-stream",
+                content: b"\x00\x01jar",
+            },
+        ];
+        assert_eq!(
+            encode_multipart(&parts, "boundary"),
+            &b"--boundary\r\n\
+              Content-Disposition: form-data; name=\"data\"\r\n\
+              Content-Type: application/json\r\n\
+              \r\n\
+              {\"file_parts\":[\"mod.jar\"]}\r\n\
+              --boundary\r\n\
+              Content-Disposition: form-data; name=\"mod.jar\"; filename=\"mod.jar\"\r\n\
+              Content-Type: application/octet-stream\r\n\
+              \r\n\
+              \x00\x01jar\r\n\
+              --boundary--\r\n"[..],
+        );
+    }
+
+    #[test]
+    fn encode_multipart_escapes_header_values() {
+        let parts = [FormPart {
+            name: "file",
+            filename: Some("a\"b\r\nc.jar"),
+            content_type: "application/octet-stream",
+            content: b"",
+        }];
+        assert_eq!(
+            encode_multipart(&parts, "boundary"),
+            &b"--boundary\r\n\
+              Content-Disposition: form-data; name=\"file\"; filename=\"a%22b%0D%0Ac.jar\"\r\n\
+              Content-Type: application/octet-stream\r\n\
+              \r\n\
+              \r\n\
+              --boundary--\r\n"[..],
+        );
+    }
+
+    #[test]
+    fn multipart_boundaries_are_random() {
+        assert_ne!(multipart_boundary(), multipart_boundary());
+    }
 }
@@ -1,4 +1,38 @@
+/// Define a set of flags stored in a `u64` bitfield, with a constant for each flag's bit
+///
+/// The `$noun` describes the flags in the generated method docs, e.g. "badges".
+macro_rules! bitflags {
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident: $noun:literal {
+            $($flag:ident = $bit:literal,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl $name {
+            $(pub const $flag: Self = Self(1 << $bit);)*
+
+            #[doc = concat!("Check whether all the ", $noun, " in `other` are in this set")]
+            pub fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            #[doc = concat!("Check whether this set has no ", $noun)]
+            pub fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+        }
+    };
+}
+
+#[cfg(feature = "modpack")]
+pub mod modpack;
 pub mod project;
+pub mod search;
 pub mod tag;
 pub mod user;
 pub mod version;
@@ -31,3 +65,22 @@ fn deserialise_optional_url<'de, D: serde::Deserializer<'de>>(
         ),
     }
 }
+
+/// Deserialise a decimal number that may have been serialised as a string
+fn deserialise_decimal<'de, D: serde::Deserializer<'de>>(de: D) -> Result<f64, D::Error> {
+    use serde::de::{Error, Unexpected};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Decimal<'a> {
+        Number(f64),
+        String(std::borrow::Cow<'a, str>),
+    }
+
+    match Decimal::deserialize(de)? {
+        Decimal::Number(number) => Ok(number),
+        Decimal::String(string) => string
+            .parse()
+            .map_err(|_| Error::invalid_value(Unexpected::Str(&string), &"a decimal number")),
+    }
+}
@@ -0,0 +1,85 @@
+//! Structures for the [Modrinth modpack format](https://docs.modrinth.com/docs/modpacks/format_definition/)
+
+use super::{project::ProjectSupportRange, version::Hashes, *};
+use std::collections::HashMap;
+
+/// The `modrinth.index.json` file found at the root of a `.mrpack` file
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModpackIndex {
+    /// The version of the format, currently always `1`
+    pub format_version: Number,
+    /// The game the modpack is for
+    pub game: ModpackGame,
+    /// The version ID of the modpack
+    pub version_id: String,
+    /// The name of the modpack
+    pub name: String,
+    /// A short description of the modpack
+    pub summary: Option<String>,
+    /// The files that need to be downloaded to install the modpack
+    pub files: Vec<ModpackFile>,
+    /// The versions of the game and loaders the modpack depends on,
+    /// e.g. `minecraft`, `forge`, `fabric-loader`, or `quilt-loader`
+    pub dependencies: HashMap<String, String>,
+}
+
+impl ModpackIndex {
+    /// Parse the contents of a `modrinth.index.json` file
+    ///
+    /// The `.mrpack` file itself is a ZIP archive, so the index has to be extracted from it first.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::modpack::ModpackIndex;
+    /// let index = ModpackIndex::from_slice(br#"{
+    ///     "formatVersion": 1,
+    ///     "game": "minecraft",
+    ///     "versionId": "1.0.0",
+    ///     "name": "Example Pack",
+    ///     "files": [],
+    ///     "dependencies": { "minecraft": "1.19.2", "fabric-loader": "0.14.10" }
+    /// }"#)?;
+    /// assert_eq!(index.dependencies["minecraft"], "1.19.2");
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn from_slice(index: &[u8]) -> serde_json::Result<Self> {
+        serde_json::from_slice(index)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModpackFile {
+    /// The destination path of the file, relative to the instance directory
+    pub path: String,
+    pub hashes: Hashes,
+    /// Which sides the file should be installed on, it's required on both if absent
+    pub env: Option<ModpackEnv>,
+    /// Links to download the file from, in order of preference
+    pub downloads: Vec<Url>,
+    /// The size of the file in bytes
+    pub file_size: Number,
+}
+
+impl ModpackFile {
+    /// Check whether the downloaded `data` matches the file's hashes
+    pub fn verify(&self, data: &[u8]) -> bool {
+        self.hashes.verify(data)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ModpackEnv {
+    /// The file's client side support range
+    pub client: ProjectSupportRange,
+    /// The file's server side support range
+    pub server: ProjectSupportRange,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive] // More games may be supported in the future
+pub enum ModpackGame {
+    Minecraft,
+}
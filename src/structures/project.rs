@@ -1,4 +1,5 @@
 use super::*;
+use std::collections::HashMap;
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Project {
@@ -44,6 +45,9 @@ pub struct Project {
     pub id: ID,
     /// The ID of the team that has ownership of this project
     pub team: ID,
+    /// The ID of the organisation that owns this project, if any
+    #[serde(default)]
+    pub organization: Option<ID>,
     /// A link to the long description of the project (only present for old projects)
     #[deprecated = "Read from `body` instead"]
     #[serde(deserialize_with = "deserialise_optional_url")]
@@ -56,6 +60,9 @@ pub struct Project {
     pub updated: UtcTime,
     /// The date the project's status was set to approved or unlisted
     pub approved: Option<UtcTime>,
+    /// The date the project was submitted for review by the moderators
+    #[serde(default)]
+    pub queued: Option<UtcTime>,
     /// The total number of users following the project
     pub followers: Number,
     /// The project's status
@@ -64,8 +71,128 @@ pub struct Project {
     pub license: License,
     /// A list of the version IDs of the project
     pub versions: Vec<ID>,
+    /// The loaders supported by any of the project's versions, if the response includes them
+    #[serde(default)]
+    pub loaders: Vec<super::version::ModLoader>,
+    /// The game versions supported by any of the project's versions, if the response includes them
+    #[serde(default)]
+    pub game_versions: Vec<String>,
     /// A list of images that have been uploaded to the project's gallery
     pub gallery: Vec<GalleryItem>,
+    /// Whether the project is earning revenue
+    #[serde(default)]
+    pub monetization_status: Option<MonetizationStatus>,
+}
+
+impl Project {
+    /// Resolve the owner of this project.
+    /// If it is owned by an organisation, its ID is returned,
+    /// otherwise the project's team is fetched to find the member with the owner role.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::project::ProjectOwner;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_mod = modrinth.get_project("AANobbMI").await?;
+    /// if let Some(ProjectOwner::User(owner)) = sodium_mod.owner(&modrinth).await? {
+    ///     assert!(owner.user.username == "jellysquid3");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn owner(&self, modrinth: &crate::Ferinth) -> crate::Result<Option<ProjectOwner>> {
+        if let Some(organization) = &self.organization {
+            return Ok(Some(ProjectOwner::Organization(organization.clone())));
+        }
+        Ok(modrinth
+            .list_team_members(&self.team)
+            .await?
+            .into_iter()
+            .find(|member| member.role == "Owner")
+            .map(|member| ProjectOwner::User(Box::new(member))))
+    }
+
+    /// Check whether the project must be installed on the client for it to work
+    pub fn required_on_client(&self) -> bool {
+        self.client_side == ProjectSupportRange::Required
+    }
+
+    /// Check whether the project must be installed on the server for it to work
+    pub fn required_on_server(&self) -> bool {
+        self.server_side == ProjectSupportRange::Required
+    }
+
+    /// Check whether the project can be installed on the client, i.e. it is required or optional
+    pub fn supported_on_client(&self) -> bool {
+        self.client_side != ProjectSupportRange::Unsupported
+    }
+
+    /// Check whether the project can be installed on the server, i.e. it is required or optional
+    pub fn supported_on_server(&self) -> bool {
+        self.server_side != ProjectSupportRange::Unsupported
+    }
+
+    /// Get the gallery images sorted by their `ordering`, with unordered images last
+    pub fn gallery_ordered(&self) -> Vec<&GalleryItem> {
+        let mut gallery = self.gallery.iter().collect::<Vec<_>>();
+        gallery.sort_by_key(|item| (item.ordering.is_none(), item.ordering));
+        gallery
+    }
+
+    /// The number of whole days that have passed since the project was last updated
+    pub fn days_since_update(&self) -> i64 {
+        (chrono::Utc::now() - self.updated).num_days()
+    }
+
+    /// Whether the project has been approved by the moderators.
+    /// This includes projects that have since been archived, unlisted, or made private.
+    pub fn is_approved(&self) -> bool {
+        matches!(
+            self.status,
+            ProjectStatus::Approved
+                | ProjectStatus::Archived
+                | ProjectStatus::Unlisted
+                | ProjectStatus::Private
+        )
+    }
+
+    /// Get the oldest and newest game versions this project supports, ordered using their release dates in `game_versions`,
+    /// as returned by the game version tag.
+    /// Versions not present in `game_versions` are ignored.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let game_versions = modrinth.list_game_versions().await?;
+    /// let sodium_mod = modrinth.get_project("AANobbMI").await?;
+    /// let (oldest, newest) = sodium_mod.game_version_range(&game_versions).unwrap();
+    /// println!("Sodium supports {} – {}", oldest, newest);
+    /// # Ok(()) }
+    /// ```
+    pub fn game_version_range(
+        &self,
+        game_versions: &[tag::GameVersion],
+    ) -> Option<(String, String)> {
+        let mut versions: Vec<_> = self
+            .game_versions
+            .iter()
+            .filter(|version| tag::GameVersion::release_date(game_versions, version).is_some())
+            .collect();
+        tag::GameVersion::sort_versions(game_versions, &mut versions);
+        Some((versions.first()?.to_string(), versions.last()?.to_string()))
+    }
+}
+
+/// The owner of a project
+#[derive(Debug, Clone)]
+pub enum ProjectOwner {
+    /// The ID of the organisation that owns the project
+    Organization(ID),
+    /// The team member that owns the project
+    User(Box<user::TeamMember>),
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -87,6 +214,63 @@ pub struct License {
     pub url: Option<Url>,
 }
 
+/// The kinds of external links a project can have
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkKind {
+    Issues,
+    Source,
+    Wiki,
+    Discord,
+    /// A donation link, with the donation platform's ID
+    Donation(String),
+}
+
+impl Project {
+    /// Get the project's external links, along with what kind of link each one is
+    pub fn links(&self) -> Vec<(LinkKind, &Url)> {
+        [
+            (LinkKind::Issues, &self.issues_url),
+            (LinkKind::Source, &self.source_url),
+            (LinkKind::Wiki, &self.wiki_url),
+            (LinkKind::Discord, &self.discord_url),
+        ]
+        .into_iter()
+        .filter_map(|(kind, url)| url.as_ref().map(|url| (kind, url)))
+        .chain(
+            self.donation_urls
+                .iter()
+                .map(|donation| (LinkKind::Donation(donation.id.clone()), &donation.url)),
+        )
+        .collect()
+    }
+
+    /// Render the project's Markdown `body` to HTML, sanitised so that it is safe to display,
+    /// e.g. in a webview, without allowing scripts or other unsafe content
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_mod = modrinth.get_project("AANobbMI").await?;
+    /// let html = sodium_mod.body_html();
+    /// assert!(!html.contains("<script"));
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "markdown")]
+    pub fn body_html(&self) -> String {
+        use pulldown_cmark::{html, Options, Parser};
+
+        let mut options = Options::empty();
+        options.insert(Options::ENABLE_TABLES);
+        options.insert(Options::ENABLE_STRIKETHROUGH);
+        options.insert(Options::ENABLE_TASKLISTS);
+        let mut unsafe_html = String::new();
+        html::push_html(&mut unsafe_html, Parser::new_ext(&self.body, options));
+        ammonia::clean(&unsafe_html)
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct DonationLink {
     /// The donation platform's ID
@@ -109,6 +293,33 @@ pub struct GalleryItem {
     pub description: Option<String>,
     /// The date and time the gallery image was created
     pub created: UtcTime,
+    /// The order of the gallery image, lower values come first
+    #[serde(default)]
+    pub ordering: Option<isize>,
+}
+
+/// The details of an image to add with [`Ferinth::add_gallery_image`](crate::Ferinth::add_gallery_image)
+///
+/// Example:
+/// ```rust
+/// # use ferinth::structures::project::GalleryImageOptions;
+/// let options = GalleryImageOptions {
+///     title: Some("Test image".into()),
+///     ..Default::default()
+/// };
+/// assert!(!options.featured);
+/// assert_eq!(options.ordering, None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GalleryImageOptions {
+    /// Whether the image should be featured in the gallery
+    pub featured: bool,
+    /// The title of the gallery image
+    pub title: Option<String>,
+    /// The description of the gallery image
+    pub description: Option<String>,
+    /// The order of the gallery image, lower values come first
+    pub ordering: Option<isize>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -117,6 +328,85 @@ pub struct ProjectDependencies {
     pub versions: Vec<version::Version>,
 }
 
+/// A project along with everything needed to show its page,
+/// returned by [`Ferinth::get_project_bundle`](crate::Ferinth::get_project_bundle)
+///
+/// The parts other than the project itself are fetched separately, so they can fail independently.
+#[derive(Debug)]
+pub struct ProjectBundle {
+    pub project: Project,
+    /// The project's public versions
+    pub versions: crate::Result<Vec<version::Version>>,
+    /// The members of the project's team
+    pub members: crate::Result<Vec<user::TeamMember>>,
+    /// The projects and versions the project depends on
+    pub dependencies: crate::Result<ProjectDependencies>,
+}
+
+/// The changes to make to a project with [`Ferinth::modify_project`](crate::Ferinth::modify_project)
+///
+/// Only the fields that are set are sent, so the rest of the project is left unchanged.
+///
+/// Example:
+/// ```rust
+/// # use ferinth::structures::project::{ProjectEdit, ProjectSupportRange};
+/// # fn main() -> Result<(), serde_json::Error> {
+/// let edits = ProjectEdit {
+///     title: Some("New title".into()),
+///     server_side: Some(ProjectSupportRange::Unsupported),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     serde_json::to_string(&edits)?,
+///     r#"{"title":"New title","server_side":"unsupported"}"#,
+/// );
+/// assert_eq!(serde_json::to_string(&ProjectEdit::default())?, "{}");
+/// # Ok(()) }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ProjectEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short description of the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A long form description of the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// A list of categories the project is in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<String>>,
+    /// A list of categories which are searchable but non-primary
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_categories: Option<Vec<String>>,
+    /// The project's client side support range
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_side: Option<ProjectSupportRange>,
+    /// The project's server side support range
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_side: Option<ProjectSupportRange>,
+    /// A link to submit bugs or issues with the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issues_url: Option<Url>,
+    /// A link to the project's source code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<Url>,
+    /// A link to the project's wiki page or other relevant information
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wiki_url: Option<Url>,
+    /// The project's Discord server invite
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discord_url: Option<Url>,
+    /// The SPDX license ID of the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_id: Option<String>,
+    /// The status to change the project to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ProjectStatus>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ResolveIDSlugResponse {
     pub id: String,
@@ -131,9 +421,76 @@ pub enum ProjectStatus {
     Unlisted,
     Archived,
     Processing,
+    /// The project has been hidden from the public by the moderators
+    Withheld,
+    /// The project will be released at a scheduled time
+    Scheduled,
+    /// The project is only visible to its members
+    Private,
     Unknown,
 }
 
+impl ProjectStatus {
+    /// Check whether the project's authors can change the project's status from this status to `requested`.
+    ///
+    /// Drafts and rejected projects can be submitted for review, submissions can be withdrawn,
+    /// and approved projects can be switched between being public, unlisted, archived, or private.
+    /// Other changes can only be made by the moderators.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::project::ProjectStatus;
+    /// assert!(ProjectStatus::Draft.can_transition_to(&ProjectStatus::Processing));
+    /// assert!(ProjectStatus::Approved.can_transition_to(&ProjectStatus::Archived));
+    /// assert!(!ProjectStatus::Rejected.can_transition_to(&ProjectStatus::Approved));
+    /// ```
+    pub fn can_transition_to(&self, requested: &Self) -> bool {
+        use ProjectStatus::*;
+        matches!(
+            (self, requested),
+            (Draft | Rejected, Processing)
+                | (Processing, Draft)
+                | (
+                    Approved | Unlisted | Archived | Private,
+                    Approved | Unlisted | Archived | Private
+                )
+        )
+    }
+
+    /// Check whether the project has been withheld or rejected by the moderators,
+    /// which hides it from the public
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::project::ProjectStatus;
+    /// assert!(ProjectStatus::Withheld.is_hidden_by_moderators());
+    /// assert!(ProjectStatus::Rejected.is_hidden_by_moderators());
+    /// assert!(!ProjectStatus::Unlisted.is_hidden_by_moderators());
+    /// ```
+    pub fn is_hidden_by_moderators(&self) -> bool {
+        matches!(self, ProjectStatus::Withheld | ProjectStatus::Rejected)
+    }
+
+    /// Check that the project's status can be changed from this status to `requested`,
+    /// returning [`Error::InvalidStatusTransition`](crate::Error::InvalidStatusTransition) if it can't
+    pub fn check_transition(&self, requested: &Self) -> crate::Result<()> {
+        self.can_transition_to(requested)
+            .then_some(())
+            .ok_or_else(|| crate::Error::InvalidStatusTransition {
+                from: self.clone(),
+                to: requested.clone(),
+            })
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum MonetizationStatus {
+    Monetized,
+    Demonetized,
+    ForceDemonetized,
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum ProjectSupportRange {
@@ -165,3 +522,85 @@ pub enum FileExt {
     SVGZ,
     RGB,
 }
+
+impl FileExt {
+    /// Get the file extension, as used in the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PNG => "png",
+            Self::JPG => "jpg",
+            Self::JPEG => "jpeg",
+            Self::BMP => "bmp",
+            Self::GIF => "gif",
+            Self::WebP => "webp",
+            Self::SVG => "svg",
+            Self::SVGZ => "svgz",
+            Self::RGB => "rgb",
+        }
+    }
+
+    /// Get the MIME type of images with this extension
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::PNG => "image/png",
+            Self::JPG | Self::JPEG => "image/jpeg",
+            Self::BMP => "image/bmp",
+            Self::GIF => "image/gif",
+            Self::WebP => "image/webp",
+            Self::SVG | Self::SVGZ => "image/svg+xml",
+            Self::RGB => "image/x-rgb",
+        }
+    }
+}
+
+/// Tracks how many times projects have been downloaded since the last time they were checked
+///
+/// The tracker can be serialised to persist the snapshot between runs.
+///
+/// Example:
+/// ```rust
+/// # use ferinth::structures::project::DownloadTracker;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), ferinth::Error> {
+/// # let modrinth = ferinth::Ferinth::default();
+/// let mut tracker = DownloadTracker::default();
+/// tracker.update(&modrinth.get_multiple_projects(&["AANobbMI", "P7dR8mSH"]).await?);
+/// // Some time later
+/// let deltas = tracker.update(&modrinth.get_multiple_projects(&["AANobbMI", "P7dR8mSH"]).await?);
+/// assert!(deltas.contains_key("AANobbMI"));
+/// # Ok(()) }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DownloadTracker {
+    /// The number of downloads each project had when it was last checked
+    pub snapshot: HashMap<ID, Number>,
+}
+
+impl DownloadTracker {
+    /// Get the number of downloads each of `projects` has had since the snapshot.
+    /// Projects that aren't in the snapshot are not included.
+    pub fn deltas(&self, projects: &[Project]) -> HashMap<ID, Number> {
+        projects
+            .iter()
+            .filter_map(|project| {
+                self.snapshot.get(&project.id).map(|previous| {
+                    (
+                        project.id.clone(),
+                        project.downloads.saturating_sub(*previous),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Get the [`deltas`](Self::deltas) of `projects`, and then update the snapshot with their current downloads
+    pub fn update(&mut self, projects: &[Project]) -> HashMap<ID, Number> {
+        let deltas = self.deltas(projects);
+        self.snapshot.extend(
+            projects
+                .iter()
+                .map(|project| (project.id.clone(), project.downloads)),
+        );
+        deltas
+    }
+}
@@ -0,0 +1,278 @@
+use super::{
+    project::{ProjectSupportRange, ProjectType},
+    *,
+};
+
+/// The order to sort search results in
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchIndex {
+    #[default]
+    Relevance,
+    Downloads,
+    Follows,
+    Newest,
+    Updated,
+}
+
+impl SearchIndex {
+    /// The name of the index, as used in the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Relevance => "relevance",
+            Self::Downloads => "downloads",
+            Self::Follows => "follows",
+            Self::Newest => "newest",
+            Self::Updated => "updated",
+        }
+    }
+}
+
+/// A condition that search results must meet
+///
+/// Facets are grouped in the same way as in the API, the results must match
+/// at least one facet from every group (i.e. the groups are ANDed, and the facets within a group are ORed).
+///
+/// Example:
+/// ```rust
+/// # use ferinth::structures::search::Facet;
+/// # fn main() -> Result<(), serde_json::Error> {
+/// // Fabric or Quilt mods, for 1.19.2
+/// let facets = [
+///     vec![
+///         Facet::Categories("fabric".into()),
+///         Facet::Categories("quilt".into()),
+///     ],
+///     vec![Facet::Versions("1.19.2".into())],
+/// ];
+/// let encoded = Facet::encode(&facets)?;
+/// assert_eq!(
+///     encoded,
+///     r#"[["categories:fabric","categories:quilt"],["versions:1.19.2"]]"#,
+/// );
+/// // This is how the facets are sent in the query string
+/// assert_eq!(
+///     url::form_urlencoded::Serializer::new(String::new())
+///         .append_pair("facets", &encoded)
+///         .finish(),
+///     "facets=%5B%5B%22categories%3Afabric%22%2C%22categories%3Aquilt%22%5D%2C%5B%22versions%3A1.19.2%22%5D%5D",
+/// );
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Facet {
+    /// The project is in this category, loaders are also categories
+    Categories(String),
+    /// The project supports this game version
+    Versions(String),
+    /// The project has this license ID
+    License(String),
+    ProjectType(ProjectType),
+    /// The project has this ID
+    ProjectId(ID),
+    /// The project's owner has this username
+    Author(String),
+    /// The project's client side support range
+    ClientSide(ProjectSupportRange),
+    /// The project's server side support range
+    ServerSide(ProjectSupportRange),
+    /// Whether the project's license is open source
+    OpenSource(bool),
+}
+
+impl Facet {
+    /// Encode the groups of `facets` to the JSON used in the query string
+    ///
+    /// ```rust
+    /// # use ferinth::structures::{project::ProjectType, search::Facet};
+    /// # fn main() -> Result<(), serde_json::Error> {
+    /// assert_eq!(
+    ///     Facet::encode(&[vec![Facet::ProjectType(ProjectType::ResourcePack)]])?,
+    ///     r#"[["project_type:resourcepack"]]"#,
+    /// );
+    /// assert_eq!(Facet::encode(&[])?, "[]");
+    /// # Ok(()) }
+    /// ```
+    pub fn encode(facets: &[Vec<Facet>]) -> serde_json::Result<String> {
+        serde_json::to_string(facets)
+    }
+}
+
+impl std::fmt::Display for Facet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn support_range(range: &ProjectSupportRange) -> &'static str {
+            match range {
+                ProjectSupportRange::Required => "required",
+                ProjectSupportRange::Optional => "optional",
+                ProjectSupportRange::Unsupported => "unsupported",
+            }
+        }
+
+        match self {
+            Self::Categories(category) => write!(f, "categories:{}", category),
+            Self::Versions(version) => write!(f, "versions:{}", version),
+            Self::License(license) => write!(f, "license:{}", license),
+            Self::ProjectType(project_type) => write!(
+                f,
+                "project_type:{}",
+                match project_type {
+                    ProjectType::Mod => "mod",
+                    ProjectType::Plugin => "plugin",
+                    ProjectType::Modpack => "modpack",
+                    ProjectType::ResourcePack => "resourcepack",
+                }
+            ),
+            Self::ProjectId(id) => write!(f, "project_id:{}", id),
+            Self::Author(author) => write!(f, "author:{}", author),
+            Self::ClientSide(range) => write!(f, "client_side:{}", support_range(range)),
+            Self::ServerSide(range) => write!(f, "server_side:{}", support_range(range)),
+            Self::OpenSource(open_source) => write!(f, "open_source:{}", open_source),
+        }
+    }
+}
+
+impl Serialize for Facet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SearchResponse {
+    /// The projects that matched the search
+    pub hits: Vec<SearchHit>,
+    /// The number of results that were skipped
+    pub offset: Number,
+    /// The number of results that were returned
+    pub limit: Number,
+    /// The total number of results that matched the search
+    pub total_hits: Number,
+}
+
+/// A page of search results from [`Ferinth::search_page`](crate::Ferinth::search_page),
+/// which remembers the search so that the following pages can be requested with [`SearchPage::next`]
+///
+/// Example:
+/// ```rust
+/// # use ferinth::structures::search::SearchIndex;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), ferinth::Error> {
+/// # let modrinth = ferinth::Ferinth::default();
+/// let mut page = modrinth.search_page("sodium", SearchIndex::Downloads, 20, &[]).await?;
+/// let mut titles: Vec<_> = page.hits.iter().map(|hit| hit.title.clone()).collect();
+/// // For example, when a "load more" button is pressed
+/// if let Some(next) = page.next(&modrinth).await? {
+///     assert!(next.offset == page.offset + page.hits.len());
+///     titles.extend(next.hits.iter().map(|hit| hit.title.clone()));
+///     page = next;
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SearchPage {
+    /// The projects on this page
+    pub hits: Vec<SearchHit>,
+    /// The number of results before this page
+    pub offset: Number,
+    /// The total number of results that matched the search
+    pub total_hits: Number,
+    query: String,
+    index: SearchIndex,
+    limit: Number,
+    facets: Vec<Vec<Facet>>,
+}
+
+impl SearchPage {
+    pub(crate) fn new(
+        response: SearchResponse,
+        query: &str,
+        index: SearchIndex,
+        limit: Number,
+        facets: &[Vec<Facet>],
+    ) -> Self {
+        Self {
+            hits: response.hits,
+            offset: response.offset,
+            total_hits: response.total_hits,
+            query: query.to_owned(),
+            index,
+            limit,
+            facets: facets.to_vec(),
+        }
+    }
+
+    /// Check whether there are more results after this page
+    pub fn has_next(&self) -> bool {
+        next_offset(self.offset, self.hits.len(), self.total_hits).is_some()
+    }
+
+    /// Get the page of results after this one, or `None` if this is the last page
+    pub async fn next(&self, modrinth: &crate::Ferinth) -> crate::Result<Option<SearchPage>> {
+        let offset = match next_offset(self.offset, self.hits.len(), self.total_hits) {
+            Some(offset) => offset,
+            None => return Ok(None),
+        };
+        let response = modrinth
+            .search(&self.query, self.index, offset, self.limit, &self.facets)
+            .await?;
+        Ok(Some(Self::new(
+            response,
+            &self.query,
+            self.index,
+            self.limit,
+            &self.facets,
+        )))
+    }
+}
+
+/// The offset of the page after the page at `offset` with `hits` results, or `None` if that is the last page
+pub(crate) fn next_offset(offset: Number, hits: usize, total_hits: Number) -> Option<Number> {
+    let next = offset + hits;
+    // An empty page is the last one too, in case `total_hits` is larger than the number of results that can be returned
+    (hits != 0 && next < total_hits).then_some(next)
+}
+
+/// A project in the results of a search
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SearchHit {
+    pub slug: String,
+    pub title: String,
+    /// A short description of the project
+    pub description: String,
+    /// A list of the categories the project is in
+    pub categories: Vec<String>,
+    /// The project's client side support range
+    pub client_side: ProjectSupportRange,
+    /// The project's server side support range
+    pub server_side: ProjectSupportRange,
+    pub project_type: ProjectType,
+    /// The total number of downloads the project has
+    pub downloads: Number,
+    /// The link to the project's icon
+    #[serde(deserialize_with = "deserialise_optional_url")]
+    pub icon_url: Option<Url>,
+    pub project_id: ID,
+    /// The username of the project's owner
+    pub author: String,
+    /// A list of the categories the project is in that should be displayed
+    #[serde(default)]
+    pub display_categories: Vec<String>,
+    /// A list of the game versions the project supports
+    pub versions: Vec<String>,
+    /// The total number of users following the project
+    pub follows: Number,
+    /// When the project was created
+    pub date_created: UtcTime,
+    /// When the project was last modified
+    pub date_modified: UtcTime,
+    /// The ID of the latest version of the project
+    pub latest_version: Option<ID>,
+    /// The SPDX license ID of the project
+    pub license: String,
+    /// The URLs of the project's gallery images
+    #[serde(default)]
+    pub gallery: Vec<Url>,
+    /// The URL of the project's featured gallery image
+    #[serde(default, deserialize_with = "deserialise_optional_url")]
+    pub featured_gallery: Option<Url>,
+}
@@ -32,6 +32,101 @@ pub struct GameVersion {
     pub major: bool,
 }
 
+impl GameVersion {
+    /// Find the release date of `version` in `game_versions`, as returned by the game version tag
+    pub fn release_date(game_versions: &[GameVersion], version: &str) -> Option<UtcTime> {
+        game_versions
+            .iter()
+            .find(|game_version| game_version.version == version)
+            .map(|game_version| game_version.date)
+    }
+
+    /// Sort `versions` from oldest to newest using their release dates in `game_versions`.
+    /// Versions not present in `game_versions` are placed at the end.
+    pub fn sort_versions<S: AsRef<str>>(game_versions: &[GameVersion], versions: &mut [S]) {
+        versions.sort_by_cached_key(|version| {
+            let date = Self::release_date(game_versions, version.as_ref());
+            (date.is_none(), date, version.as_ref().to_owned())
+        });
+    }
+
+    /// Resolve `minimum` to the release versions in `game_versions` that are equal to or newer than it,
+    /// in the order they are in `game_versions`. For example, `1.20` resolves to `1.20`, `1.20.1`, ..., `1.21`, and so on.
+    ///
+    /// If `include_snapshots` is set, the snapshots released since the last release older than `minimum` are included too.
+    ///
+    /// [`Error::InvalidGameVersion`](crate::Error::InvalidGameVersion) is returned if `minimum` isn't a release version number,
+    /// or if no release versions match it.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::tag::GameVersion;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let game_versions: Vec<GameVersion> = serde_json::from_value(serde_json::json!([
+    ///     { "version": "1.20.1", "version_type": "release", "date": "2023-06-12T13:25:51Z", "major": false },
+    ///     { "version": "1.20", "version_type": "release", "date": "2023-06-02T08:36:17Z", "major": true },
+    ///     { "version": "23w18a", "version_type": "snapshot", "date": "2023-05-03T11:10:09Z", "major": false },
+    ///     { "version": "1.19.4", "version_type": "release", "date": "2023-03-14T12:56:18Z", "major": false },
+    ///     { "version": "23w07a", "version_type": "snapshot", "date": "2023-02-15T14:38:11Z", "major": false },
+    /// ]))?;
+    /// assert_eq!(GameVersion::resolve_minimum(&game_versions, "1.20", false)?, ["1.20.1", "1.20"]);
+    /// assert_eq!(
+    ///     GameVersion::resolve_minimum(&game_versions, "1.20", true)?,
+    ///     ["1.20.1", "1.20", "23w18a"],
+    /// );
+    /// assert!(GameVersion::resolve_minimum(&game_versions, "1.20.x", false).is_err());
+    /// assert!(GameVersion::resolve_minimum(&game_versions, "1.21", false).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn resolve_minimum(
+        game_versions: &[GameVersion],
+        minimum: &str,
+        include_snapshots: bool,
+    ) -> crate::Result<Vec<String>> {
+        let minimum_number = Self::parse_release(minimum)
+            .ok_or_else(|| crate::Error::InvalidGameVersion(minimum.to_owned()))?;
+        let release_number = |game_version: &GameVersion| {
+            (game_version.version_type == GameVersionType::Release)
+                .then(|| Self::parse_release(&game_version.version))
+                .flatten()
+        };
+        if !game_versions.iter().any(|game_version| {
+            release_number(game_version).is_some_and(|number| number >= minimum_number)
+        }) {
+            return Err(crate::Error::InvalidGameVersion(minimum.to_owned()));
+        }
+        // The snapshots for `minimum` are the ones released after the release before it
+        let previous_release = game_versions
+            .iter()
+            .filter(|game_version| {
+                release_number(game_version).is_some_and(|number| number < minimum_number)
+            })
+            .map(|game_version| game_version.date)
+            .max();
+
+        Ok(game_versions
+            .iter()
+            .filter(|game_version| match release_number(game_version) {
+                Some(number) => number >= minimum_number,
+                None => {
+                    include_snapshots
+                        && game_version.version_type == GameVersionType::Snapshot
+                        && previous_release.map_or(true, |previous| game_version.date > previous)
+                }
+            })
+            .map(|game_version| game_version.version.clone())
+            .collect())
+    }
+
+    /// Parse a release version number such as `1.20.1` into its numeric components
+    fn parse_release(version: &str) -> Option<Vec<u32>> {
+        version
+            .split('.')
+            .map(|component| component.parse().ok())
+            .collect()
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct License {
     /// The short identifier of the license
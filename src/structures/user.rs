@@ -16,6 +16,85 @@ pub struct User {
     /// The time at which the user was created
     pub created: UtcTime,
     pub role: UserRole,
+    /// The badges the user has been awarded
+    #[serde(default)]
+    pub badges: Badges,
+    /// The user's payout information, only visible to the user itself when authenticated
+    #[serde(default)]
+    pub payout_data: Option<PayoutData>,
+}
+
+/// A user along with everything needed to show their profile,
+/// returned by [`Ferinth::get_user_full`](crate::Ferinth::get_user_full)
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserProfile {
+    pub user: User,
+    /// The projects that the user owns
+    pub projects: Vec<super::project::Project>,
+    /// The notifications the user has received, only present if the user is the authenticated user
+    pub notifications: Option<Vec<Notification>>,
+    /// The projects the user has followed, only present if the user is the authenticated user
+    pub followed_projects: Option<Vec<super::project::Project>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PayoutData {
+    /// The amount of money the user has earned, in USD
+    #[serde(deserialize_with = "deserialise_decimal")]
+    pub balance: f64,
+    /// The wallet the user receives payouts with
+    pub payout_wallet: Option<PayoutWallet>,
+    /// The type of the payout address
+    pub payout_wallet_type: Option<PayoutWalletType>,
+    /// The address payouts are sent to
+    pub payout_address: Option<String>,
+}
+
+bitflags! {
+    /// A set of badges in bitflag format
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::user::Badges;
+    /// let badges = Badges(0b0110_0000);
+    /// assert!(badges.contains(Badges::CONTRIBUTOR));
+    /// assert!(badges.contains(Badges::TRANSLATOR));
+    /// assert!(!badges.contains(Badges::MIDAS));
+    /// ```
+    pub struct Badges: "badges" {
+        MIDAS = 0,
+        EARLY_MODPACK_ADOPTER = 1,
+        EARLY_RESPACK_ADOPTER = 2,
+        EARLY_PLUGIN_ADOPTER = 3,
+        ALPHA_TESTER = 4,
+        CONTRIBUTOR = 5,
+        TRANSLATOR = 6,
+    }
+}
+
+bitflags! {
+    /// A set of team member permissions in bitflag format
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::user::TeamPermissions;
+    /// let permissions = TeamPermissions(0b0000_0101);
+    /// assert!(permissions.contains(TeamPermissions::UPLOAD_VERSION));
+    /// assert!(permissions.contains(TeamPermissions::EDIT_DETAILS));
+    /// assert!(!permissions.contains(TeamPermissions::DELETE_PROJECT));
+    /// ```
+    pub struct TeamPermissions: "permissions" {
+        UPLOAD_VERSION = 0,
+        DELETE_VERSION = 1,
+        EDIT_DETAILS = 2,
+        EDIT_BODY = 3,
+        MANAGE_INVITES = 4,
+        REMOVE_MEMBER = 5,
+        EDIT_MEMBER = 6,
+        DELETE_PROJECT = 7,
+        VIEW_ANALYTICS = 8,
+        VIEW_PAYOUTS = 9,
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -24,19 +103,9 @@ pub struct TeamMember {
     pub team_id: ID,
     pub user: User,
     pub role: String,
-    /// The user's permissions in bitflag format
+    /// The user's permissions in the team
     /// (requires authorisation to view)
-    ///
-    /// In order from first to eighth bit, they indicate:
-    /// - UPLOAD_VERSION
-    /// - DELETE_VERSION
-    /// - EDIT_DETAILS
-    /// - EDIT_BODY
-    /// - MANAGE_INVITES
-    /// - REMOVE_MEMBER
-    /// - EDIT_MEMBER
-    /// - DELETE_PROJECT
-    pub permissions: Option<u8>,
+    pub permissions: Option<TeamPermissions>,
     /// Whether the user has accepted membership of the team
     /// (requires authorisation to view)
     pub accepted: bool,
@@ -64,6 +133,7 @@ pub struct Notification {
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Report {
+    pub id: ID,
     pub report_type: String,
     /// The ID of the item being report
     pub item_id: ID,
@@ -98,6 +168,7 @@ pub struct NotificationAction {
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
 pub enum ReportItemType {
     Project,
     Version,
@@ -111,6 +182,22 @@ pub enum NotificationType {
     TeamInvite,
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum PayoutWallet {
+    PayPal,
+    Venmo,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutWalletType {
+    Email,
+    Phone,
+    UserHandle,
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
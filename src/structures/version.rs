@@ -1,4 +1,4 @@
-use super::*;
+use super::{tag::GameVersion, *};
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Version {
@@ -14,8 +14,8 @@ pub struct Version {
     /// The release channel for this version
     pub version_type: VersionType,
     /// The mod loaders that this version supports
-    pub loaders: Vec<String>,
-    /// Whether the version is featured or not
+    pub loaders: Vec<ModLoader>,
+    /// Whether the version is featured on the project's page
     pub featured: bool,
     pub id: ID,
     /// The ID of the project this version is for
@@ -24,13 +24,254 @@ pub struct Version {
     pub author_id: ID,
     pub date_published: UtcTime,
     /// The number of times this version has been downloaded
-    pub downloads: usize,
+    pub downloads: u64,
     /// A link to the version's changelog
     #[deprecated = "Read from `changelog` instead"]
     #[serde(deserialize_with = "deserialise_optional_url")]
     pub changelog_url: Option<Url>,
     /// A list of files available for download
     pub files: Vec<VersionFile>,
+    /// The version's status
+    #[serde(default)]
+    pub status: Option<VersionStatus>,
+    /// The status the version's author has requested that it be changed to
+    #[serde(default)]
+    pub requested_status: Option<VersionStatus>,
+}
+
+impl Version {
+    /// Whether this version is visible to the public, rather than being a draft, unlisted, or scheduled.
+    /// Versions without a status are assumed to be visible.
+    pub fn is_public(&self) -> bool {
+        self.status.as_ref().map_or(true, |status| {
+            matches!(status, VersionStatus::Listed | VersionStatus::Archived)
+        })
+    }
+
+    /// Sort `versions` by their number of downloads, from most to least downloaded.
+    /// Versions with the same number of downloads are sorted from newest to oldest.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::version::Version;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let version = |id: &str, downloads: u64, date_published: &str| {
+    ///     serde_json::from_value::<Version>(serde_json::json!({
+    ///         "name": id, "version_number": id, "changelog": null, "dependencies": [],
+    ///         "game_versions": ["1.19.2"], "version_type": "release", "loaders": ["fabric"],
+    ///         "featured": false, "id": id, "project_id": "AANobbMI", "author_id": "TEZXhE2U",
+    ///         "date_published": date_published, "downloads": downloads, "changelog_url": null,
+    ///         "files": [],
+    ///     }))
+    /// };
+    /// let mut versions = vec![
+    ///     version("older", 100, "2022-08-01T00:00:00Z")?,
+    ///     version("least", 10, "2022-10-01T00:00:00Z")?,
+    ///     version("most", 1000, "2022-06-01T00:00:00Z")?,
+    ///     version("newer", 100, "2022-09-01T00:00:00Z")?,
+    /// ];
+    /// Version::sort_by_downloads(&mut versions);
+    /// let ids: Vec<_> = versions.iter().map(|version| version.id.as_str()).collect();
+    /// assert_eq!(ids, ["most", "newer", "older", "least"]);
+    /// # Ok(()) }
+    /// ```
+    pub fn sort_by_downloads(versions: &mut [Version]) {
+        versions.sort_by(|a, b| {
+            b.downloads
+                .cmp(&a.downloads)
+                .then_with(|| b.date_published.cmp(&a.date_published))
+        });
+    }
+
+    /// Get the primary file of this version,
+    /// or the first file if none of them are marked as primary
+    pub fn primary_file(&self) -> Option<&VersionFile> {
+        self.files
+            .iter()
+            .find(|file| file.primary)
+            .or_else(|| self.files.first())
+    }
+
+    /// Get the file of this version for `loader`, for versions that have a file for each loader.
+    ///
+    /// The files whose names mention the loader are preferred, with the primary file first.
+    /// If no file names mention the loader, the [primary file](Version::primary_file) is returned.
+    pub fn primary_file_for(&self, loader: ModLoader) -> Option<&VersionFile> {
+        let mut files = self
+            .files
+            .iter()
+            .filter(|file| file.mentions_loader(&loader));
+        files
+            .clone()
+            .find(|file| file.primary)
+            .or_else(|| files.next())
+            .or_else(|| self.primary_file())
+    }
+
+    /// Get the changelog of this version.
+    /// If this is an old version that links to its changelog instead, it's downloaded from `changelog_url`.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_version = modrinth.get_version("xuWxRZPd").await?;
+    /// assert!(sodium_version.fetch_changelog(&modrinth).await?.is_some());
+    /// # Ok(()) }
+    /// ```
+    pub async fn fetch_changelog(&self, modrinth: &crate::Ferinth) -> crate::Result<Option<String>> {
+        if let Some(changelog) = self.changelog.as_ref().filter(|changelog| !changelog.is_empty()) {
+            return Ok(Some(changelog.clone()));
+        }
+        #[allow(deprecated)]
+        match &self.changelog_url {
+            Some(changelog_url) => Ok(Some(
+                String::from_utf8_lossy(&modrinth.download(changelog_url.clone()).await?).into(),
+            )),
+            None => Ok(self.changelog.clone()),
+        }
+    }
+
+    /// Parse the `version_number` as a [semantic version](https://semver.org/), allowing a leading `v`,
+    /// or return `None` if it isn't one so that callers can fall back to comparing `date_published`.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let sodium_version = modrinth.get_version("xuWxRZPd").await?;
+    /// // Sodium's version numbers aren't semantic versions
+    /// assert!(sodium_version.semver().is_none());
+    /// # Ok(()) }
+    /// ```
+    pub fn semver(&self) -> Option<semver::Version> {
+        let version = self.version_number.trim();
+        semver::Version::parse(version.strip_prefix('v').unwrap_or(version)).ok()
+    }
+
+    /// Check whether this version supports the game version `game_version`.
+    /// The game versions are listed individually rather than as ranges, so this must be an exact match.
+    pub fn supports_game_version(&self, game_version: &str) -> bool {
+        self.game_versions
+            .iter()
+            .any(|supported| supported == game_version)
+    }
+
+    /// Get the oldest game version this version supports,
+    /// ordered by the release dates in `game_versions` from [`Ferinth::list_game_versions`](crate::Ferinth::list_game_versions).
+    /// Game versions not present in `game_versions` are ignored.
+    ///
+    /// Example:
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ferinth::Error> {
+    /// # let modrinth = ferinth::Ferinth::default();
+    /// let game_versions = modrinth.list_game_versions().await?;
+    /// let sodium_version = modrinth.get_version("xuWxRZPd").await?;
+    /// assert!(sodium_version.min_game_version(&game_versions) == Some("1.19"));
+    /// # Ok(()) }
+    /// ```
+    pub fn min_game_version(&self, game_versions: &[GameVersion]) -> Option<&str> {
+        self.dated_game_versions(game_versions)
+            .min_by_key(|(date, _)| *date)
+            .map(|(_, version)| version)
+    }
+
+    /// Get the newest game version this version supports,
+    /// ordered by the release dates in `game_versions` from [`Ferinth::list_game_versions`](crate::Ferinth::list_game_versions).
+    /// Game versions not present in `game_versions` are ignored.
+    pub fn max_game_version(&self, game_versions: &[GameVersion]) -> Option<&str> {
+        self.dated_game_versions(game_versions)
+            .max_by_key(|(date, _)| *date)
+            .map(|(_, version)| version)
+    }
+
+    /// Pair the game versions this version supports with their release dates in `game_versions`
+    fn dated_game_versions<'a, 'b>(
+        &'a self,
+        game_versions: &'b [GameVersion],
+    ) -> impl Iterator<Item = (UtcTime, &'a str)> + 'b
+    where
+        'a: 'b,
+    {
+        self.game_versions.iter().filter_map(|version| {
+            GameVersion::release_date(game_versions, version).map(|date| (date, version.as_str()))
+        })
+    }
+
+    /// Check whether the primary file of this version is a JAR file
+    pub fn is_jar(&self) -> bool {
+        self.primary_file().is_some_and(VersionFile::is_jar)
+    }
+
+    /// Check whether the primary file of this version is a ZIP file
+    pub fn is_zip(&self) -> bool {
+        self.primary_file().is_some_and(VersionFile::is_zip)
+    }
+
+    /// Check whether the primary file of this version is a Modrinth modpack
+    pub fn is_mrpack(&self) -> bool {
+        self.primary_file().is_some_and(VersionFile::is_mrpack)
+    }
+}
+
+/// Get the versions in `new` that are not in `old`, compared by their IDs.
+/// The versions are returned in the order they appear in `new`.
+///
+/// Example:
+/// ```rust
+/// # use ferinth::structures::version::diff_versions;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), ferinth::Error> {
+/// # let modrinth = ferinth::Ferinth::default();
+/// let new = modrinth.list_versions("AANobbMI").await?;
+/// let old = &new[1..];
+/// let added = diff_versions(old, &new);
+/// assert!(added.len() == 1);
+/// assert!(added[0].id == new[0].id);
+/// # Ok(()) }
+/// ```
+pub fn diff_versions<'a>(old: &[Version], new: &'a [Version]) -> Vec<&'a Version> {
+    let old = old
+        .iter()
+        .map(|version| &version.id)
+        .collect::<std::collections::HashSet<_>>();
+    new.iter()
+        .filter(|version| !old.contains(&version.id))
+        .collect()
+}
+
+/// Group `versions` by the loader and game version combinations they support.
+/// A version supporting multiple loaders or game versions appears under every combination it supports.
+///
+/// Example:
+/// ```rust
+/// # use ferinth::structures::version::{compatibility_matrix, ModLoader};
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), ferinth::Error> {
+/// # let modrinth = ferinth::Ferinth::default();
+/// let sodium_versions = modrinth.list_versions("AANobbMI").await?;
+/// let matrix = compatibility_matrix(&sodium_versions);
+/// assert!(matrix.contains_key(&(ModLoader::Fabric, "1.19.2".into())));
+/// # Ok(()) }
+/// ```
+pub fn compatibility_matrix(
+    versions: &[Version],
+) -> std::collections::HashMap<(ModLoader, String), Vec<&Version>> {
+    let mut matrix = std::collections::HashMap::<_, Vec<_>>::new();
+    for version in versions {
+        for loader in &version.loaders {
+            for game_version in &version.game_versions {
+                matrix
+                    .entry((loader.clone(), game_version.clone()))
+                    .or_default()
+                    .push(version);
+            }
+        }
+    }
+    matrix
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -46,6 +287,81 @@ pub struct VersionFile {
     pub size: Number,
 }
 
+impl VersionFile {
+    /// Get the extension of the file's name, without the leading dot.
+    /// Any query string or fragment that ended up in the filename is ignored,
+    /// and for double extensions such as `.tar.gz` only the last one is returned.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::version::{Hashes, VersionFile};
+    /// let mut file = VersionFile {
+    ///     hashes: Hashes { sha512: "".into(), sha1: "".into() },
+    ///     url: "https://cdn.modrinth.com/data/AANobbMI/versions/mc1.19.2-0.4.4/sodium-fabric-mc1.19.2-0.4.4%2Bbuild.18.jar".parse().unwrap(),
+    ///     filename: "sodium-fabric-mc1.19.2-0.4.4+build.18.jar".into(),
+    ///     primary: true,
+    ///     size: 0,
+    /// };
+    /// assert_eq!(file.extension(), Some("jar"));
+    ///
+    /// file.filename = "world.tar.gz?download=1".into();
+    /// assert_eq!(file.extension(), Some("gz"));
+    ///
+    /// file.filename = ".gitignore".into();
+    /// assert_eq!(file.extension(), None);
+    /// ```
+    pub fn extension(&self) -> Option<&str> {
+        let name = self.filename.split(['?', '#']).next()?;
+        let name = name.rsplit(['/', '\\']).next()?;
+        match name.rsplit_once('.') {
+            Some((stem, ext)) if !stem.is_empty() && !ext.is_empty() => Some(ext),
+            _ => None,
+        }
+    }
+
+    /// Check whether the file's name mentions `loader`, as one of the words in it, ignoring case
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::version::{Hashes, ModLoader, VersionFile};
+    /// let file = VersionFile {
+    ///     hashes: Hashes { sha512: "".into(), sha1: "".into() },
+    ///     url: "https://cdn.modrinth.com/data/AANobbMI/versions/mc1.19.2-0.4.4/sodium-fabric-mc1.19.2-0.4.4%2Bbuild.18.jar".parse().unwrap(),
+    ///     filename: "sodium-NeoForge-mc1.19.2-0.4.4+build.18.jar".into(),
+    ///     primary: true,
+    ///     size: 0,
+    /// };
+    /// assert!(file.mentions_loader(&ModLoader::NeoForge));
+    /// assert!(!file.mentions_loader(&ModLoader::Forge));
+    /// ```
+    pub fn mentions_loader(&self, loader: &ModLoader) -> bool {
+        self.filename
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|word| word.eq_ignore_ascii_case(loader.as_str()))
+    }
+
+    /// Check whether the file is a JAR file
+    pub fn is_jar(&self) -> bool {
+        self.has_extension("jar")
+    }
+
+    /// Check whether the file is a ZIP file
+    pub fn is_zip(&self) -> bool {
+        self.has_extension("zip")
+    }
+
+    /// Check whether the file is a Modrinth modpack
+    pub fn is_mrpack(&self) -> bool {
+        self.has_extension("mrpack")
+    }
+
+    /// Check whether the file's extension is `ext`, ignoring case
+    pub fn has_extension(&self, ext: &str) -> bool {
+        self.extension()
+            .is_some_and(|this| this.eq_ignore_ascii_case(ext))
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Hashes {
     /// The SHA512 hash of the version file
@@ -54,6 +370,108 @@ pub struct Hashes {
     pub sha1: String,
 }
 
+impl Hashes {
+    /// Check whether `data` matches these hashes.
+    /// The SHA512 hash is used if it's available, otherwise the SHA1 hash is used.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::version::Hashes;
+    /// let hashes = Hashes {
+    ///     sha512: "".into(),
+    ///     sha1: "a9993e364706816aba3e25717850c26c9cd0d89d".into(),
+    /// };
+    /// assert!(hashes.verify(b"abc"));
+    /// assert!(!hashes.verify(b"abcd"));
+    /// ```
+    pub fn verify(&self, data: &[u8]) -> bool {
+        let mut context = self.context();
+        context.update(data);
+        self.check(&context.finish())
+    }
+
+    /// Create a context to incrementally compute the SHA512 hash, or the SHA1 hash if there is no SHA512 hash
+    pub(crate) fn context(&self) -> HashContext {
+        use sha2::Digest;
+
+        if self.sha512.is_empty() {
+            HashContext::Sha1(sha1::Sha1::new())
+        } else {
+            HashContext::Sha512(sha2::Sha512::new())
+        }
+    }
+
+    /// Check whether `digest`, computed using a context from [`Hashes::context`], matches the expected hash
+    pub(crate) fn check(&self, digest: &[u8]) -> bool {
+        let expected = if self.sha512.is_empty() {
+            &self.sha1
+        } else {
+            &self.sha512
+        };
+        let actual = digest
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        actual.eq_ignore_ascii_case(expected)
+    }
+}
+
+/// A hash being computed incrementally, created by [`Hashes::context`]
+pub(crate) enum HashContext {
+    Sha1(sha1::Sha1),
+    Sha512(sha2::Sha512),
+}
+
+impl HashContext {
+    /// Add `data` to the hash
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        use sha2::Digest;
+
+        match self {
+            Self::Sha1(context) => context.update(data),
+            Self::Sha512(context) => context.update(data),
+        }
+    }
+
+    /// Finish computing the hash and return its digest
+    pub(crate) fn finish(self) -> Vec<u8> {
+        use sha2::Digest;
+
+        match self {
+            Self::Sha1(context) => context.finalize().to_vec(),
+            Self::Sha512(context) => context.finalize().to_vec(),
+        }
+    }
+}
+
+/// The metadata of a version to create with [`Ferinth::create_version`](crate::Ferinth::create_version)
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VersionCreate {
+    pub name: String,
+    /// The version's number.
+    /// Ideally, this will follow semantic versioning.
+    pub version_number: String,
+    pub changelog: Option<String>,
+    /// A list of specific versions of projects that this version depends on
+    pub dependencies: Vec<Dependency>,
+    /// A list of Minecraft versions that this version supports
+    pub game_versions: Vec<String>,
+    /// The release channel for this version
+    pub version_type: VersionType,
+    /// The mod loaders that this version supports
+    pub loaders: Vec<ModLoader>,
+    /// Whether the version is featured on the project's page
+    pub featured: bool,
+    /// The status the version should have once it's created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<VersionStatus>,
+    /// The ID of the project this version is for
+    pub project_id: ID,
+    /// The name of the uploaded file that is the version's primary file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_file: Option<String>,
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct HashesBody {
     pub hashes: Vec<String>,
@@ -62,7 +480,7 @@ pub struct HashesBody {
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct LatestVersionBody {
-    pub loaders: Vec<String>,
+    pub loaders: Vec<ModLoader>,
     pub game_versions: Vec<String>,
 }
 
@@ -70,7 +488,7 @@ pub struct LatestVersionBody {
 pub struct LatestVersionsBody {
     pub hashes: Vec<String>,
     pub algorithm: HashAlgorithm,
-    pub loaders: Vec<String>,
+    pub loaders: Vec<ModLoader>,
     pub game_versions: Vec<String>,
 }
 
@@ -91,6 +509,28 @@ pub enum HashAlgorithm {
     SHA1,
 }
 
+impl HashAlgorithm {
+    /// The name of the algorithm, as used in the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SHA512 => "sha512",
+            Self::SHA1 => "sha1",
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionStatus {
+    Listed,
+    Archived,
+    Draft,
+    Unlisted,
+    /// The version will be released at a scheduled time
+    Scheduled,
+    Unknown,
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum VersionType {
@@ -108,3 +548,118 @@ pub enum DependencyType {
     Incompatible,
     Embedded,
 }
+
+/// A mod loader or plugin platform
+///
+/// Loaders that don't have a variant yet are represented by `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ModLoader {
+    Fabric,
+    Forge,
+    NeoForge,
+    Quilt,
+    LiteLoader,
+    Rift,
+    Bukkit,
+    Spigot,
+    Paper,
+    Purpur,
+    Folia,
+    Sponge,
+    BungeeCord,
+    Waterfall,
+    Velocity,
+    Iris,
+    OptiFine,
+    Canvas,
+    Datapack,
+    Minecraft,
+    Other(String),
+}
+
+impl ModLoader {
+    /// The name of the loader that Modrinth uses
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Fabric => "fabric",
+            Self::Forge => "forge",
+            Self::NeoForge => "neoforge",
+            Self::Quilt => "quilt",
+            Self::LiteLoader => "liteloader",
+            Self::Rift => "rift",
+            Self::Bukkit => "bukkit",
+            Self::Spigot => "spigot",
+            Self::Paper => "paper",
+            Self::Purpur => "purpur",
+            Self::Folia => "folia",
+            Self::Sponge => "sponge",
+            Self::BungeeCord => "bungeecord",
+            Self::Waterfall => "waterfall",
+            Self::Velocity => "velocity",
+            Self::Iris => "iris",
+            Self::OptiFine => "optifine",
+            Self::Canvas => "canvas",
+            Self::Datapack => "datapack",
+            Self::Minecraft => "minecraft",
+            Self::Other(loader) => loader,
+        }
+    }
+}
+
+impl std::fmt::Display for ModLoader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ModLoader {
+    type Err = std::convert::Infallible;
+
+    /// Parse a loader name, this never fails as unknown loaders are parsed to `Other`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use ferinth::structures::version::ModLoader;
+    /// assert_eq!("fabric".parse(), Ok(ModLoader::Fabric));
+    /// assert_eq!("NeoForge".parse(), Ok(ModLoader::NeoForge));
+    /// assert_eq!("babric".parse(), Ok(ModLoader::Other("babric".into())));
+    /// ```
+    fn from_str(loader: &str) -> Result<Self, Self::Err> {
+        Ok(match loader.to_ascii_lowercase().as_str() {
+            "fabric" => Self::Fabric,
+            "forge" => Self::Forge,
+            "neoforge" => Self::NeoForge,
+            "quilt" => Self::Quilt,
+            "liteloader" => Self::LiteLoader,
+            "rift" => Self::Rift,
+            "bukkit" => Self::Bukkit,
+            "spigot" => Self::Spigot,
+            "paper" => Self::Paper,
+            "purpur" => Self::Purpur,
+            "folia" => Self::Folia,
+            "sponge" => Self::Sponge,
+            "bungeecord" => Self::BungeeCord,
+            "waterfall" => Self::Waterfall,
+            "velocity" => Self::Velocity,
+            "iris" => Self::Iris,
+            "optifine" => Self::OptiFine,
+            "canvas" => Self::Canvas,
+            "datapack" => Self::Datapack,
+            "minecraft" => Self::Minecraft,
+            _ => Self::Other(loader.into()),
+        })
+    }
+}
+
+impl Serialize for ModLoader {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ModLoader {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let loader = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Ok(loader.parse().unwrap_or_else(|never| match never {}))
+    }
+}
@@ -5,7 +5,8 @@ pub trait UrlJoinExt {
 }
 
 impl UrlJoinExt for Url {
-    /// Join all the `paths` provided
+    /// Join all the `paths` provided.
+    /// The URL is treated as a directory whether or not it ends with a slash.
     ///
     /// Example:
     /// ```ignore
@@ -17,6 +18,10 @@ impl UrlJoinExt for Url {
     /// ```
     fn join_all<S: Into<String>>(&self, mut paths: Vec<S>) -> Self {
         let mut url = self.clone();
+        // Without a trailing slash, the last segment would be replaced when joining paths
+        if !url.path().ends_with('/') {
+            url.set_path(&format!("{}/", url.path()));
+        }
         let last = paths.pop().unwrap();
         for segment in paths {
             let mut segment = segment.into();
@@ -26,3 +31,35 @@ impl UrlJoinExt for Url {
         url.join(&last.into()).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_all_with_trailing_slash() {
+        let base = Url::parse("https://api.modrinth.com/v2/").unwrap();
+        assert_eq!(
+            base.join_all(vec!["project", "sodium", "version"]).as_str(),
+            "https://api.modrinth.com/v2/project/sodium/version",
+        );
+    }
+
+    #[test]
+    fn join_all_without_trailing_slash() {
+        let base = Url::parse("https://api.modrinth.com/v2").unwrap();
+        assert_eq!(
+            base.join_all(vec!["project", "sodium", "version"]).as_str(),
+            "https://api.modrinth.com/v2/project/sodium/version",
+        );
+    }
+
+    #[test]
+    fn join_all_onto_host_root() {
+        let base = Url::parse("http://localhost:8000").unwrap();
+        assert_eq!(
+            base.join_all(vec!["search"]).as_str(),
+            "http://localhost:8000/search",
+        );
+    }
+}